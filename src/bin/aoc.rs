@@ -0,0 +1,435 @@
+use adventofcode2021::error::AocError;
+use adventofcode2021::exit_on_error;
+use adventofcode2021::gen;
+use adventofcode2021::input::{InputArgs, Source};
+use adventofcode2021::json;
+use adventofcode2021::registry::{Day, Run, Selection};
+use adventofcode2021::repl::{self, Repl};
+use adventofcode2021::serve;
+use rayon::prelude::*;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::net::TcpListener;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc <all|DAY|FROM..TO> [--part 1|2] [--time | --bench N] \
+                     [--format text|json] [--jobs N] [--example | --input-dir DIR | INPUT | -]\n       \
+                     aoc gen DAY [--seed N] [--size N] [--answers FILE]\n       \
+                     aoc repl DAY [--example | --input-dir DIR | INPUT]\n       \
+                     aoc serve [--port N]";
+const GEN_USAGE: &str = "usage: aoc gen DAY [--seed N] [--size N] [--answers FILE]";
+const REPL_USAGE: &str = "usage: aoc repl DAY [--example | --input-dir DIR | INPUT]";
+const SERVE_USAGE: &str = "usage: aoc serve [--port N]";
+
+/// Port of `aoc serve` without `--port`.
+const DEFAULT_PORT: u16 = 2021;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One JSON object per line for every day and part.
+    Json,
+}
+
+struct Args {
+    selection: Selection,
+    part: Option<u32>,
+    input: InputArgs,
+    time: bool,
+    bench: Option<usize>,
+    format: Format,
+    /// Number of days solved at the same time.
+    jobs: Option<usize>,
+}
+
+/// Wall-clock samples of one step of a day: `parse`, `part1` or `part2`.
+struct Step {
+    name: String,
+    samples: Vec<Duration>,
+}
+
+impl Step {
+    fn sorted(&self) -> Vec<Duration> {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        samples
+    }
+
+    fn median(&self) -> Duration {
+        let samples = self.sorted();
+        samples[samples.len() / 2]
+    }
+}
+
+fn parse_args() -> Result<Args, Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let mut selection = None;
+    let mut part = None;
+    let mut input = InputArgs::default();
+    let mut time = false;
+    let mut bench = None;
+    let mut format = Format::Text;
+    let mut jobs = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let p = args.next().ok_or("--part needs a value")?.parse()?;
+                if p != 1 && p != 2 {
+                    return Err(format!("no such part: {}", p).into());
+                }
+                part = Some(p);
+            }
+            "--time" => time = true,
+            "--bench" => {
+                let n = args.next().ok_or("--bench needs a value")?.parse()?;
+                if n == 0 {
+                    return Err("--bench needs at least one run".into());
+                }
+                bench = Some(n);
+            }
+            "--jobs" => {
+                let n = args.next().ok_or("--jobs needs a value")?.parse()?;
+                if n == 0 {
+                    return Err("--jobs needs at least one thread".into());
+                }
+                jobs = Some(n);
+            }
+            "--format" => {
+                format = match args.next().ok_or("--format needs a value")?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    f => return Err(format!("unknown format: {}", f).into()),
+                }
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ if selection.is_none() && !arg.starts_with("--") => selection = Some(arg.parse()?),
+            _ if input.parse_option(&arg, &mut args)? => (),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+    Ok(Args {
+        selection: selection.ok_or(USAGE)?,
+        part,
+        input,
+        time,
+        bench,
+        format,
+        jobs,
+    })
+}
+
+fn format_duration(d: Duration) -> String {
+    let micros = d.as_secs_f64() * 1e6;
+    if micros < 1e3 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1e6 {
+        format!("{:.1}ms", micros / 1e3)
+    } else {
+        format!("{:.2}s", micros / 1e6)
+    }
+}
+
+/// Runs `day` once, or as many times as `--bench` asks. Returns the first
+/// run and the samples of all of them.
+fn measure(day: &Day, lines: &[String], args: &Args) -> Result<(Run, Vec<Step>), AocError> {
+    let step = |name: String, d| Step {
+        name,
+        samples: vec![d],
+    };
+    let first = day.solve_timed(lines, args.part)?;
+    let mut steps = vec![step("parse".to_string(), first.parse)];
+    for p in &first.parts {
+        steps.push(step(format!("part{}", p.part), p.duration));
+    }
+    for _ in 1..args.bench.unwrap_or(1) {
+        let run = day.solve_timed(lines, args.part)?;
+        let durations = [run.parse]
+            .into_iter()
+            .chain(run.parts.iter().map(|p| p.duration));
+        for (step, d) in steps.iter_mut().zip(durations) {
+            step.samples.push(d);
+        }
+    }
+    Ok((first, steps))
+}
+
+/// Prints a JSON object per part, with the median duration when benchmarking.
+fn print_json(day: u32, source: &Source, run: &Run, steps: &[Step], args: &Args) {
+    let nanos = |name: &str| {
+        let step = steps.iter().find(|s| s.name == name).unwrap();
+        step.median().as_nanos()
+    };
+    for p in &run.parts {
+        let object = json::Object::new()
+            .raw("day", day)
+            .raw("part", p.part)
+            .str("input", &source.to_string())
+            .str("type", p.answer.kind())
+            .raw("answer", p.answer.to_json())
+            .raw("duration_ns", nanos(&format!("part{}", p.part)))
+            .raw("parse_ns", nanos("parse"))
+            .raw("runs", args.bench.unwrap_or(1));
+        println!("{}", object);
+    }
+}
+
+fn print_steps(steps: &[Step], args: &Args) {
+    if let Some(n) = args.bench {
+        println!("bench: {} run(s), min / median / max", n);
+        for step in steps {
+            let sorted = step.sorted();
+            println!(
+                "  {:<5} {:>9} / {:>9} / {:>9}",
+                step.name,
+                format_duration(sorted[0]),
+                format_duration(step.median()),
+                format_duration(sorted[sorted.len() - 1])
+            );
+        }
+    } else if args.time {
+        let times: Vec<_> = steps
+            .iter()
+            .map(|s| format!("{} {}", s.name, format_duration(s.samples[0])))
+            .collect();
+        println!("time: {}", times.join(", "));
+    }
+}
+
+/// Table of the (median) step times of every day that succeeded.
+fn print_summary(days: &[(u32, Vec<Step>)]) {
+    let columns = ["parse", "part1", "part2"];
+    println!("--- Summary ---");
+    println!(
+        "{:>5} {:>9} {:>9} {:>9} {:>9}",
+        "day", columns[0], columns[1], columns[2], "total"
+    );
+    let mut totals = [Duration::ZERO; 4];
+    for (number, steps) in days {
+        let mut row = format!("{:>5}", number);
+        let mut day_total = Duration::ZERO;
+        for (i, column) in columns.iter().enumerate() {
+            match steps.iter().find(|s| s.name == *column) {
+                Some(step) => {
+                    let d = step.median();
+                    day_total += d;
+                    totals[i] += d;
+                    row += &format!(" {:>9}", format_duration(d));
+                }
+                None => row += &format!(" {:>9}", "-"),
+            }
+        }
+        totals[3] += day_total;
+        println!("{} {:>9}", row, format_duration(day_total));
+    }
+    let totals: Vec<_> = totals.iter().map(|&d| format_duration(d)).collect();
+    println!(
+        "{:>5} {:>9} {:>9} {:>9} {:>9}",
+        "total", totals[0], totals[1], totals[2], totals[3]
+    );
+}
+
+/// Answers and timings of a day, or why it failed.
+type Outcome = Result<(Run, Vec<Step>), String>;
+
+/// A solver that panics fails its own day and leaves the others running.
+fn run_day(day: &Day, source: &Source, args: &Args) -> Outcome {
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        let lines = source.read_lines().map_err(|e| e.to_string())?;
+        measure(day, &lines, args).map_err(|e| e.to_string())
+    }));
+    run.unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "no reason given".to_string());
+        Err(format!(
+            "day {}: the solver panicked: {}",
+            day.number, reason
+        ))
+    })
+}
+
+/// Prints the outcome of a day, returning its timings if it succeeded.
+fn report(day: &Day, source: &Source, outcome: Outcome, args: &Args) -> Option<Vec<Step>> {
+    if args.format == Format::Text {
+        println!("--- Day {} ---", day.number);
+    }
+    match outcome {
+        Ok((run, steps)) => {
+            match args.format {
+                Format::Text => {
+                    run.print();
+                    print_steps(&steps, args);
+                }
+                Format::Json => print_json(day.number, source, &run, &steps, args),
+            }
+            Some(steps)
+        }
+        Err(e) => {
+            if args.format == Format::Json {
+                let object = json::Object::new()
+                    .raw("day", day.number)
+                    .str("input", &source.to_string())
+                    .str("error", &e);
+                println!("{}", object);
+            }
+            eprintln!("{}: {}", source, e);
+            None
+        }
+    }
+}
+
+/// `aoc gen`: writes a random input of one day to stdout, and optionally the
+/// answers it was built around as an answers manifest.
+fn generate(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+    let mut answers = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = args.next().ok_or("--seed needs a value")?.parse()?,
+            "--size" => size = Some(args.next().ok_or("--size needs a value")?.parse()?),
+            "--answers" => answers = Some(args.next().ok_or("--answers needs a file")?),
+            "-h" | "--help" => {
+                println!("{}", GEN_USAGE);
+                process::exit(0);
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(arg.parse()?),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+    let day = day.ok_or(GEN_USAGE)?;
+    let generated = gen::generate(day, seed, size).map_err(|e| e.to_string())?;
+    if let Some(path) = answers {
+        let (part1, part2) = generated
+            .answers
+            .ok_or_else(|| format!("the answers of day {} are not known in advance", day))?;
+        fs::write(path, format!("part1: {}\npart2: {}\n", part1, part2))?;
+    }
+    let mut out = BufWriter::new(io::stdout().lock());
+    for line in &generated.lines {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// `aoc repl`: steps through the simulation of one day with the commands
+/// read from stdin, see `help` once it runs.
+fn repl(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut day = None;
+    let mut input = InputArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", REPL_USAGE);
+                process::exit(0);
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(arg.parse()?),
+            _ if input.parse_option(&arg, &mut args)? => (),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+    let day = day.ok_or(REPL_USAGE)?;
+    repl::check_day(day).map_err(|e| e.to_string())?;
+    let source = input.source(day)?;
+    if source == Source::Stdin {
+        return Err("the commands come from stdin, so the input has to be a file".into());
+    }
+    let lines = source
+        .read_lines()
+        .map_err(|e| format!("{}: {}", source, e))?;
+    let mut repl = Repl::new(day, lines).map_err(|e| format!("{}: {}", source, e))?;
+    let stdin = io::stdin();
+    let prompt = stdin.is_terminal();
+    repl.run(stdin.lock(), &mut io::stdout().lock(), prompt)?;
+    Ok(())
+}
+
+/// `aoc serve`: answers `POST /day/{n}/part/{p}` on localhost, see the
+/// `serve` module. Port 0 picks a free one.
+fn serve(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut port = DEFAULT_PORT;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => port = args.next().ok_or("--port needs a value")?.parse()?,
+            "-h" | "--help" => {
+                println!("{}", SERVE_USAGE);
+                process::exit(0);
+            }
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("listening on http://{}", listener.local_addr()?);
+    serve::serve(listener)?;
+    Ok(())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    match env::args().nth(1).as_deref() {
+        Some("gen") => return generate(env::args().skip(2)),
+        Some("repl") => return repl(env::args().skip(2)),
+        Some("serve") => return serve(env::args().skip(2)),
+        _ => (),
+    }
+    let args = parse_args()?;
+    let days: Vec<_> = args.selection.days().collect();
+    if days.is_empty() {
+        return Err("no solved days in selection".into());
+    }
+    if args.input.is_explicit() && days.len() > 1 {
+        return Err("an input file can only be given for a single day".into());
+    }
+    let sources = days
+        .iter()
+        .map(|day| args.input.source(day.number))
+        .collect::<Result<Vec<_>, _>>()?;
+    let timed = args.time || args.bench.is_some();
+    let mut failed = 0;
+    let mut measured = Vec::new();
+    let mut handle =
+        |day: &Day, source: &Source, outcome: Outcome| match report(day, source, outcome, &args) {
+            Some(steps) => measured.push((day.number, steps)),
+            None => failed += 1,
+        };
+    match args.jobs {
+        Some(jobs) => {
+            // Days finish in any order, so the output waits for all of them.
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+            let outcomes: Vec<_> = pool.install(|| {
+                days.par_iter()
+                    .zip(&sources)
+                    .map(|(day, source)| run_day(day, source, &args))
+                    .collect()
+            });
+            for ((day, source), outcome) in days.iter().zip(&sources).zip(outcomes) {
+                handle(day, source, outcome);
+            }
+        }
+        None => {
+            for (day, source) in days.iter().zip(&sources) {
+                handle(day, source, run_day(day, source, &args));
+            }
+        }
+    }
+    if args.format == Format::Text && timed && days.len() > 1 {
+        print_summary(&measured);
+    }
+    if failed > 0 {
+        return Err(format!("{} day(s) failed", failed).into());
+    }
+    Ok(())
+}
+
+fn main() {
+    exit_on_error(run());
+}
@@ -1,25 +1,38 @@
-use adventofcode2021::get_lines;
+use adventofcode2021::{day1, exit_on_error, get_lines_with, registry};
+use std::cell::Cell;
 use std::error::Error;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let nums: Vec<u32> = get_lines()?.filter_map(|s| s.parse().ok()).collect();
-    task1(&nums);
-    task2(&nums);
+fn run() -> Result<(), Box<dyn Error>> {
+    // The lines borrow the option parser, which still sets the window.
+    let window = Cell::new(None);
+    let lines = get_lines_with("[--window N] ", |arg, rest| {
+        if arg != "--window" {
+            return Ok(false);
+        }
+        let n = rest
+            .next()
+            .ok_or("--window needs a value")?
+            .parse()
+            .map_err(|e| format!("bad --window: {}", e))?;
+        if n == 0 {
+            return Err("--window needs at least one depth".to_string());
+        }
+        window.set(Some(n));
+        Ok(true)
+    })?;
+    match window.get() {
+        Some(window) => {
+            let report = day1::sweep(lines, window).map_err(|e| e.in_day(1).to_string())?;
+            println!("{}", report);
+        }
+        None => {
+            let lines: Vec<String> = lines.collect();
+            registry::get(1).unwrap().run(&lines, None)?;
+        }
+    }
     Ok(())
 }
 
-fn task1(nums: &Vec<u32>) {
-    println!("Task1 {}", nums.windows(2).filter(|d| d[0] < d[1]).count());
-}
-
-fn task2(nums: &Vec<u32>) {
-    println!(
-        "Task2 {:?}",
-        nums.windows(3)
-            .map(|s| s.iter().sum())
-            .collect::<Vec<u32>>()
-            .windows(2)
-            .filter(|d| d[0] < d[1])
-            .count()
-    );
+fn main() {
+    exit_on_error(run());
 }
@@ -1,58 +1,32 @@
-use adventofcode2021::get_lines;
+use adventofcode2021::day2::{self, AimingSubmarine, Command, Day2, SimpleSubmarine, Submarine};
+use adventofcode2021::solution::Solution;
+use adventofcode2021::{exit_on_error, get_lines_with, registry};
 use std::error::Error;
-use std::fmt::Debug;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let commands: Vec<String> = get_lines()?.collect();
-    task1(commands.iter());
-    task2(commands.iter());
-    Ok(())
+/// Prints where `submarine` is after every move.
+fn trace<S: Submarine>(name: &str, mut submarine: S, commands: &[Command]) {
+    println!("{}:", name);
+    day2::navigate(&mut submarine, commands, |step, submarine| {
+        println!("  {:<24} {}", step.to_string(), day2::describe(submarine));
+    });
 }
 
-fn task1<'a, T>(commands: T)
-where
-    T: Iterator<Item = &'a String> + Debug,
-{
-    let mut horizontal_position = 0;
-    let mut depth = 0;
-    for c in commands {
-        let v: Vec<&str> = c.split(" ").collect();
-        if v.len() < 2 {
-            continue;
-        }
-
-        match (v[0], v[1].parse::<i32>()) {
-            ("forward", Ok(x)) => horizontal_position += x,
-            ("down", Ok(x)) => depth += x,
-            ("up", Ok(x)) => depth -= x,
-            _ => println!("no match"),
-        };
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut traced = false;
+    let lines: Vec<String> = get_lines_with("[--trace] ", |arg, _| {
+        traced |= arg == "--trace";
+        Ok(arg == "--trace")
+    })?
+    .collect();
+    if traced {
+        let commands = Day2::parse(&lines).map_err(|e| e.in_day(2).to_string())?;
+        trace("simple submarine", SimpleSubmarine::default(), &commands);
+        trace("aiming submarine", AimingSubmarine::default(), &commands);
     }
-    println!("Task1: {}", depth * horizontal_position);
+    registry::get(2).unwrap().run(&lines, None)?;
+    Ok(())
 }
 
-fn task2<'a, T>(commands: T)
-where
-    T: Iterator<Item = &'a String> + Debug,
-{
-    let mut horizontal_position = 0;
-    let mut depth = 0;
-    let mut aim = 0;
-    for c in commands {
-        let v: Vec<&str> = c.split(" ").collect();
-        if v.len() < 2 {
-            continue;
-        }
-
-        match (v[0], v[1].parse::<i32>()) {
-            ("forward", Ok(x)) => {
-                horizontal_position += x;
-                depth += x * aim;
-            }
-            ("down", Ok(x)) => aim += x,
-            ("up", Ok(x)) => aim -= x,
-            _ => println!("no match"),
-        };
-    }
-    println!("Task1: {}", depth * horizontal_position);
+fn main() {
+    exit_on_error(run());
 }
@@ -0,0 +1,25 @@
+use adventofcode2021::{day24, exit_on_error, get_lines_with, registry};
+use std::error::Error;
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut trace = None;
+    let lines: Vec<String> = get_lines_with("[--trace MODEL] ", |arg, rest| match arg {
+        "--trace" => {
+            trace = Some(rest.next().ok_or("--trace needs a model number")?);
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?
+    .collect();
+    match trace {
+        Some(model) => day24::trace(&lines, &model)?,
+        None => {
+            registry::get(24).unwrap().run(&lines, None)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    exit_on_error(run());
+}
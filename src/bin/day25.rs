@@ -0,0 +1,30 @@
+use adventofcode2021::{day25, exit_on_error, get_lines_with, registry};
+use std::error::Error;
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut render = None;
+    let lines: Vec<String> = get_lines_with("[--render STEP,...] ", |arg, rest| match arg {
+        "--render" => {
+            let steps = rest.next().ok_or("--render needs a list of steps")?;
+            let steps = steps
+                .split(',')
+                .map(|s| s.parse().map_err(|_| format!("bad step {:?}", s)))
+                .collect::<Result<Vec<usize>, _>>()?;
+            render = Some(steps);
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?
+    .collect();
+    match render {
+        Some(steps) => day25::render(&lines, &steps)?,
+        None => {
+            registry::get(25).unwrap().run(&lines, None)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    exit_on_error(run());
+}
@@ -0,0 +1,170 @@
+//! Fields of up to 64 bits packed into bytes, most significant bit first.
+
+use std::error::Error;
+use std::fmt;
+
+/// A read past the last bit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndOfBits {
+    /// Bit the read started at.
+    pub position: usize,
+    pub wanted: usize,
+    pub left: usize,
+}
+
+impl fmt::Display for EndOfBits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "wanted {} bits at bit {}, only {} left",
+            self.wanted, self.position, self.left
+        )
+    }
+}
+
+impl Error for EndOfBits {}
+
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    len: usize,
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> BitReader<'a> {
+        BitReader::with_len(bytes, bytes.len() * 8)
+    }
+
+    /// Reads only the first `len` bits of `bytes`.
+    pub fn with_len(bytes: &'a [u8], len: usize) -> BitReader<'a> {
+        assert!(
+            len <= bytes.len() * 8,
+            "{} bits don't fit in {} bytes",
+            len,
+            bytes.len()
+        );
+        BitReader { bytes, len, pos: 0 }
+    }
+
+    /// Number of bits read so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.len - self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Moves to bit `pos`, which may be the end but not beyond.
+    pub fn seek(&mut self, pos: usize) {
+        assert!(pos <= self.len, "bit {} is past the end", pos);
+        self.pos = pos;
+    }
+
+    /// Reads a `width` bits wide number, `width` being at most 64.
+    pub fn read(&mut self, width: usize) -> Result<u64, EndOfBits> {
+        assert!(width <= 64, "can't read {} bits at once", width);
+        if width > self.remaining() {
+            return Err(EndOfBits {
+                position: self.pos,
+                wanted: width,
+                left: self.remaining(),
+            });
+        }
+        let mut value = 0;
+        let mut left = width;
+        while left > 0 {
+            let offset = self.pos % 8;
+            let take = left.min(8 - offset);
+            let byte = self.bytes[self.pos / 8] >> (8 - offset - take);
+            value = value << take | (byte & (0xff >> (8 - take))) as u64;
+            self.pos += take;
+            left -= take;
+        }
+        Ok(value)
+    }
+
+    pub fn read_bit(&mut self) -> Result<bool, EndOfBits> {
+        self.read(1).map(|b| b == 1)
+    }
+}
+
+/// Writers holding as many bits order like the numbers they spell.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> BitWriter {
+        BitWriter::default()
+    }
+
+    /// Appends the lowest `width` bits of `value`, `width` being at most 64.
+    pub fn write(&mut self, value: u64, width: usize) {
+        assert!(width <= 64, "can't write {} bits at once", width);
+        debug_assert!(
+            width == 64 || value >> width == 0,
+            "{} doesn't fit in {} bits",
+            value,
+            width
+        );
+        for i in (0..width).rev() {
+            self.write_bit(value >> i & 1 == 1);
+        }
+    }
+
+    pub fn write_bit(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Appends all the bits written to `other`.
+    pub fn append(&mut self, other: &BitWriter) {
+        let mut bits = other.reader();
+        while !bits.is_empty() {
+            let width = bits.remaining().min(64);
+            self.write(bits.read(width).unwrap(), width);
+        }
+    }
+
+    /// Bit `i` of those written so far.
+    pub fn get(&self, i: usize) -> Option<bool> {
+        (i < self.len).then(|| self.bytes[i / 8] >> (7 - i % 8) & 1 == 1)
+    }
+
+    /// Number of bits set.
+    pub fn count_ones(&self) -> usize {
+        self.bytes.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Number of bits written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bits written so far, the last byte padded with zeros.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Reads back the bits written so far.
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader::with_len(&self.bytes, self.len)
+    }
+}
@@ -0,0 +1,149 @@
+//! Day 1: Sonar Sweep, counting depth increases.
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Solution};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// How the sums of `window` consecutive depths change from one to the next.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    pub window: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// Longest run of increases as the lines, counted from 0, of the first
+    /// depth of its first window and the last depth of its last window.
+    pub longest_rise: Option<(usize, usize)>,
+}
+
+impl SweepReport {
+    /// Number of increases in a row of [`SweepReport::longest_rise`].
+    pub fn longest_rise_len(&self) -> usize {
+        self.longest_rise
+            .map_or(0, |(start, end)| end - start + 1 - self.window)
+    }
+}
+
+impl fmt::Display for SweepReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "window {}: {} increases, {} decreases, {} plateaus",
+            self.window, self.increases, self.decreases, self.plateaus
+        )?;
+        match self.longest_rise {
+            Some((start, end)) => write!(
+                f,
+                "longest rise: {} increases in a row, lines {} to {}",
+                self.longest_rise_len(),
+                start + 1,
+                end + 1
+            ),
+            None => write!(f, "longest rise: none"),
+        }
+    }
+}
+
+/// Compares window sums one depth at a time. Two neighbouring windows share
+/// all but their first and last depth, so comparing those two is enough and
+/// only the last `window` depths are kept.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    recent: VecDeque<u32>,
+    /// Depths seen so far.
+    lines: usize,
+    /// First line of the current run of increases.
+    rise_start: Option<usize>,
+    report: SweepReport,
+}
+
+impl Sweep {
+    pub fn new(window: usize) -> Sweep {
+        assert!(window > 0, "windows need at least one depth");
+        Sweep {
+            recent: VecDeque::with_capacity(window + 1),
+            lines: 0,
+            rise_start: None,
+            report: SweepReport {
+                window,
+                ..SweepReport::default()
+            },
+        }
+    }
+
+    pub fn push(&mut self, depth: u32) {
+        let line = self.lines;
+        self.lines += 1;
+        self.recent.push_back(depth);
+        if self.recent.len() <= self.report.window {
+            return;
+        }
+        let dropped = self.recent.pop_front().unwrap();
+        let report = &mut self.report;
+        if depth > dropped {
+            report.increases += 1;
+            let start = *self.rise_start.get_or_insert(line - report.window);
+            let longest = report.longest_rise.map_or(0, |(s, e)| e - s);
+            if line - start > longest {
+                report.longest_rise = Some((start, line));
+            }
+            return;
+        }
+        if depth < dropped {
+            report.decreases += 1;
+        } else {
+            report.plateaus += 1;
+        }
+        self.rise_start = None;
+    }
+
+    pub fn report(&self) -> &SweepReport {
+        &self.report
+    }
+}
+
+/// Sweeps the depths of `lines` as they come, without keeping them all.
+pub fn sweep<I>(lines: I, window: usize) -> Result<SweepReport, AocError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut sweep = Sweep::new(window);
+    for (i, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref();
+        sweep.push(parse_at(line, line).map_err(|e| e.at_line(i))?);
+    }
+    Ok(sweep.report)
+}
+
+fn count_increases(nums: &[u32], window: usize) -> usize {
+    let mut sweep = Sweep::new(window);
+    for &depth in nums {
+        sweep.push(depth);
+    }
+    sweep.report.increases
+}
+
+/// Part 1 counts depth increases, part 2 the increases of three-measurement sums.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(lines: &[String]) -> Result<Vec<u32>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, s)| parse_at(s, s).map_err(|e| e.at_line(i)))
+            .collect()
+    }
+
+    fn part1(nums: &Vec<u32>) -> Result<Answer, AocError> {
+        Ok(count_increases(nums, 1).into())
+    }
+
+    fn part2(nums: &Vec<u32>) -> Result<Answer, AocError> {
+        Ok(count_increases(nums, 3).into())
+    }
+}
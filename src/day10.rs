@@ -0,0 +1,126 @@
+//! Day 10: Syntax Scoring, corrupted and incomplete bracket lines.
+
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+/// Part 1 scores the corrupted lines, part 2 the middle completion score.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(lines: &[String]) -> Result<Vec<String>, AocError> {
+        for (i, line) in lines.iter().enumerate() {
+            if let Some((col, c)) = line.char_indices().find(|&(_, c)| !is_bracket(c)) {
+                return Err(AocError::new(format!("unexpected char {:?}", c))
+                    .at_column(col)
+                    .at_line(i));
+            }
+        }
+        Ok(lines.to_vec())
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, AocError> {
+        Ok(task1(lines).into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, AocError> {
+        Ok(task2(lines).into())
+    }
+}
+
+fn is_bracket(c: char) -> bool {
+    matches!(c, '(' | '[' | '{' | '<' | ')' | ']' | '}' | '>')
+}
+
+fn corrupted_score(c: &char) -> u32 {
+    match *c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => unreachable!("unexpected char for score"),
+    }
+}
+
+fn get_opposite(c: &char) -> char {
+    match *c {
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        '>' => '<',
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!("unexpected char for score"),
+    }
+}
+
+fn check_corrupted(s: &str) -> u32 {
+    let mut stack = Vec::new();
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if stack.pop() != Some(get_opposite(&c)) {
+                    return corrupted_score(&c);
+                }
+            }
+            _ => unreachable!("unexpected char"),
+        };
+    }
+    0
+}
+
+fn task1(lines: &[String]) -> u32 {
+    let mut res = 0;
+    for line in lines {
+        res += check_corrupted(line);
+    }
+    res
+}
+
+fn incomplete_score(mut vec: Vec<char>) -> u64 {
+    let mut res = 0;
+    while let Some(c) = vec.pop() {
+        res *= 5;
+        res += match get_opposite(&c) {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => unreachable!("unexpected char for score"),
+        }
+    }
+    res
+}
+fn check_incomplete(s: &str) -> u64 {
+    let mut stack = Vec::new();
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if stack.pop() != Some(get_opposite(&c)) {
+                    // corrupted
+                    return 0;
+                }
+            }
+            _ => unreachable!("unexpected char"),
+        };
+    }
+    incomplete_score(stack)
+}
+
+fn task2(lines: &[String]) -> u64 {
+    let mut res = Vec::new();
+    for line in lines {
+        let v = check_incomplete(line);
+        if v > 0 {
+            res.push(v);
+        }
+    }
+    res.sort();
+    // No incomplete lines means nothing to autocomplete.
+    res.get(res.len() / 2).copied().unwrap_or(0)
+}
@@ -0,0 +1,202 @@
+//! Day 11: Dumbo Octopus, flashing energy levels.
+
+use crate::error::AocError;
+use crate::frames::{Frame, Frames};
+use crate::grid::Grid;
+use crate::repl::{condition_count, Simulation};
+use crate::solution::{Answer, Solution};
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+/// Steps after which the octopuses are taken to never flash all at once.
+const STEP_LIMIT: usize = 10_000;
+
+fn never_in_sync() -> AocError {
+    AocError::new(format!(
+        "the octopuses don't all flash at once within {} steps",
+        STEP_LIMIT
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct EnergyLevelMap {
+    levels: Grid<u32>,
+}
+
+impl EnergyLevelMap {
+    fn new(lines: &[String]) -> Result<EnergyLevelMap, AocError> {
+        Ok(EnergyLevelMap {
+            levels: Grid::parse_digits(lines)?,
+        })
+    }
+    fn inc(&mut self) {
+        for level in self.levels.values_mut() {
+            *level += 1;
+        }
+    }
+    fn inc_adjacent(&mut self, x: usize, y: usize) {
+        for pos in self.levels.neighbors8(x, y) {
+            self.levels[pos] += 1;
+        }
+    }
+    fn flash(&mut self) -> u32 {
+        let mut res = 0;
+        let mut was_flash = false;
+        for y in 0..self.levels.height() {
+            for x in 0..self.levels.width() {
+                if self.levels[(x, y)] >= 10 && self.levels[(x, y)] < 100 {
+                    self.inc_adjacent(x, y);
+                    self.levels[(x, y)] = 100;
+                    was_flash = true;
+                    res += 1;
+                }
+            }
+        }
+        if was_flash {
+            res += self.flash();
+        }
+        res
+    }
+    fn reset_levels(&mut self) {
+        for level in self.levels.values_mut() {
+            if *level >= 10 {
+                *level = 0;
+            }
+        }
+    }
+    /// Advances one step, returning how many octopuses flashed.
+    pub fn step(&mut self) -> u32 {
+        self.inc();
+        let res = self.flash();
+        self.reset_levels();
+        res
+    }
+    fn is_flash_all(&self) -> bool {
+        self.levels.values().all(|&level| level == 0)
+    }
+    /// Octopuses that just flashed are white, the others brighter the more
+    /// energy they have.
+    fn frame(&self) -> Frame {
+        Frame::grey(&self.levels, |_, &level| match level {
+            0 => 255,
+            _ => level as u8 * 20,
+        })
+    }
+}
+
+/// Writes the map before the first step and after every step until all the
+/// octopuses flash at once, at most [`STEP_LIMIT`] steps. Returns the number
+/// of frames.
+pub fn frames(lines: &[String], dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let mut map = EnergyLevelMap::new(lines)?;
+    let mut frames = Frames::new(dir, "day11")?;
+    frames.push(&map.frame())?;
+    for _ in 0..STEP_LIMIT {
+        map.step();
+        frames.push(&map.frame())?;
+        if map.is_flash_all() {
+            return Ok(frames.count());
+        }
+    }
+    Err(never_in_sync().into())
+}
+
+impl fmt::Display for EnergyLevelMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.levels.rows() {
+            for &level in row {
+                if (10..100).contains(&level) {
+                    write!(f, "^")?;
+                } else if level >= 100 {
+                    write!(f, "*")?;
+                } else {
+                    write!(f, "{}", level)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        write!(f, "")
+    }
+}
+
+/// Steps the octopuses for `aoc repl`, counting all their flashes.
+struct Steps {
+    map: EnergyLevelMap,
+    flashes: u64,
+}
+
+impl Simulation for Steps {
+    fn step(&mut self) -> Option<String> {
+        let flashes = self.map.step();
+        self.flashes += flashes as u64;
+        let all = if self.map.is_flash_all() {
+            ", all at once"
+        } else {
+            ""
+        };
+        Some(format!("{} flashes{}", flashes, all))
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["sync"] => Ok(self.map.is_flash_all()),
+            ["flashes", ..] => Ok(self.flashes >= condition_count(condition)?),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("sync", "all the octopuses just flashed"),
+            ("flashes N", "N flashes in total"),
+        ]
+    }
+}
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{} flashes so far", self.map, self.flashes)
+    }
+}
+
+/// The octopuses of `lines`, step by step.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    Ok(Box::new(Steps {
+        map: EnergyLevelMap::new(lines)?,
+        flashes: 0,
+    }))
+}
+
+/// Part 1 counts the flashes in 100 steps, part 2 finds the first step everyone flashes.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = EnergyLevelMap;
+
+    fn parse(lines: &[String]) -> Result<EnergyLevelMap, AocError> {
+        EnergyLevelMap::new(lines)
+    }
+
+    fn part1(map: &EnergyLevelMap) -> Result<Answer, AocError> {
+        let mut map = map.clone();
+        let mut res = 0;
+        for _ in 0..100 {
+            res += map.step();
+        }
+        Ok(res.into())
+    }
+
+    fn part2(map: &EnergyLevelMap) -> Result<Answer, AocError> {
+        let mut map = map.clone();
+        let mut res = 0;
+        while !map.is_flash_all() {
+            if res == STEP_LIMIT {
+                return Err(never_in_sync());
+            }
+            map.step();
+            res += 1;
+        }
+        Ok(res.into())
+    }
+}
@@ -0,0 +1,111 @@
+//! Day 12: Passage Pathing, counting paths through the caves.
+
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub struct Cave {
+    code: String,
+    connections: Vec<Rc<RefCell<Cave>>>,
+}
+
+impl Cave {
+    fn new(code: String) -> Rc<RefCell<Cave>> {
+        Rc::new(RefCell::new(Cave {
+            code,
+            connections: Vec::new(),
+        }))
+    }
+
+    fn is_big(&self) -> bool {
+        self.code.to_uppercase() == self.code
+    }
+}
+
+fn init_cave_system(lines: &[String]) -> Result<Rc<RefCell<Cave>>, AocError> {
+    let mut caves: HashMap<&str, Rc<RefCell<Cave>>> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let (cave1, cave2) = line
+            .split_once('-')
+            .ok_or_else(|| AocError::new("expected `<cave>-<cave>`").at_line(i))?;
+        if !caves.contains_key(cave1) {
+            caves.insert(cave1, Cave::new(String::from(cave1)));
+        }
+        if !caves.contains_key(cave2) {
+            caves.insert(cave2, Cave::new(String::from(cave2)));
+        }
+        caves[cave1]
+            .borrow_mut()
+            .connections
+            .push(caves[cave2].clone());
+        caves[cave2]
+            .borrow_mut()
+            .connections
+            .push(caves[cave1].clone());
+    }
+    caves
+        .get("start")
+        .cloned()
+        .ok_or_else(|| AocError::new("no start cave"))
+}
+
+fn find_all_path(node: Rc<RefCell<Cave>>, seen: &mut Vec<String>) -> u32 {
+    let node = node.borrow();
+    if node.code == "end" {
+        return 1;
+    }
+    seen.push(node.code.clone());
+    let mut res = 0;
+    for c in &node.connections {
+        if c.borrow().is_big() || !seen.contains(&c.borrow().code) {
+            res += find_all_path(c.clone(), seen);
+        }
+    }
+    seen.pop();
+    res
+}
+
+fn find_all_path_2(node: Rc<RefCell<Cave>>, twice_small: bool, seen: &mut Vec<String>) -> u32 {
+    let node = node.borrow();
+    if node.code == "end" {
+        return 1;
+    }
+    seen.push(node.code.clone());
+    let mut res = 0;
+    for c in &node.connections {
+        if c.borrow().code == "start" {
+            continue;
+        }
+        if c.borrow().is_big() {
+            res += find_all_path_2(c.clone(), twice_small, seen);
+        } else {
+            let visited = seen.contains(&c.borrow().code);
+            if !visited || !twice_small {
+                res += find_all_path_2(c.clone(), twice_small || visited, seen);
+            }
+        }
+    }
+    seen.pop();
+    res
+}
+
+/// Paths visiting small caves at most once, and with one small cave visited twice.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Rc<RefCell<Cave>>;
+
+    fn parse(lines: &[String]) -> Result<Rc<RefCell<Cave>>, AocError> {
+        init_cave_system(lines)
+    }
+
+    fn part1(start: &Rc<RefCell<Cave>>) -> Result<Answer, AocError> {
+        Ok(find_all_path(start.clone(), &mut Vec::new()).into())
+    }
+
+    fn part2(start: &Rc<RefCell<Cave>>) -> Result<Answer, AocError> {
+        Ok(find_all_path_2(start.clone(), false, &mut Vec::new()).into())
+    }
+}
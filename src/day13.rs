@@ -0,0 +1,230 @@
+//! Day 13: Transparent Origami, folding dotted paper.
+
+use crate::error::{parse_at, AocError};
+use crate::frames::{Frame, Frames};
+use crate::grid::Grid;
+use crate::point::{parse_point2, Bounds2, Point2};
+use crate::repl::{condition_count, Simulation};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Fold {
+    X(i64),
+    Y(i64),
+}
+
+impl Fold {
+    /// Where `dot` lands, dots before the fold line stay in place.
+    fn apply(&self, dot: Point2) -> Point2 {
+        match *self {
+            Fold::X(v) if dot.x > v => Point2::new(v + v - dot.x, dot.y),
+            Fold::Y(v) if dot.y > v => Point2::new(dot.x, v + v - dot.y),
+            _ => dot,
+        }
+    }
+}
+
+impl fmt::Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fold::X(v) => write!(f, "fold along x={}", v),
+            Fold::Y(v) => write!(f, "fold along y={}", v),
+        }
+    }
+}
+
+impl FromStr for Fold {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^fold along (x|y)=(\d+)$").unwrap();
+        let caps = re
+            .captures(s)
+            .ok_or_else(|| AocError::new("expected `fold along <x|y>=<n>`"))?;
+        let v = parse_at(s, caps.get(2).unwrap().as_str())?;
+        if &caps[1] == "x" {
+            Ok(Fold::X(v))
+        } else {
+            Ok(Fold::Y(v))
+        }
+    }
+}
+
+fn parse_dot(line: &str) -> Result<Point2, AocError> {
+    let dot = parse_point2(line, line)?;
+    if dot.x < 0 || dot.y < 0 {
+        return Err(AocError::new("dots can't be left of or above the paper"));
+    }
+    Ok(dot)
+}
+
+#[derive(Debug, Clone)]
+struct Paper {
+    dots: HashSet<Point2>,
+}
+impl Paper {
+    fn new() -> Paper {
+        Paper {
+            dots: HashSet::new(),
+        }
+    }
+    fn fold(&mut self, f: &Fold) {
+        self.dots = self.dots.iter().map(|&dot| f.apply(dot)).collect();
+    }
+    /// From the top left corner of the paper to the farthest dots.
+    fn bounds(&self) -> Bounds2 {
+        Bounds2::around(self.dots.iter().copied().chain([Point2::ORIGIN])).unwrap()
+    }
+    /// Dots are white on the `visible` part, the folded away rest of the
+    /// unfolded paper is grey.
+    fn frame(&self, paper: Bounds2, visible: Bounds2) -> Frame {
+        let (width, height) = (paper.width() as usize, paper.height() as usize);
+        Frame::Grey(Grid::from_fn(width, height, |x, y| {
+            let p = Point2::from((x, y));
+            if !visible.contains(p) {
+                64
+            } else if self.dots.contains(&p) {
+                255
+            } else {
+                0
+            }
+        }))
+    }
+}
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bounds = self.bounds();
+        for p in bounds.points() {
+            write!(f, "{}", if self.dots.contains(&p) { '#' } else { '.' })?;
+            if p.x == bounds.max.x {
+                writeln!(f)?;
+            }
+        }
+        write!(f, "")
+    }
+}
+
+#[derive(Debug)]
+pub struct Manual {
+    paper: Paper,
+    folds: Vec<Fold>,
+}
+
+impl Manual {
+    fn new() -> Manual {
+        Manual {
+            paper: Paper::new(),
+            folds: Vec::new(),
+        }
+    }
+}
+
+/// Writes the paper before and after every fold, at the size of the unfolded
+/// paper. Returns the number of frames.
+pub fn frames(lines: &[String], dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let manual = Day13::parse(lines)?;
+    let mut paper = manual.paper.clone();
+    let bounds = paper.bounds();
+    let mut visible = bounds;
+    let mut frames = Frames::new(dir, "day13")?;
+    frames.push(&paper.frame(bounds, visible))?;
+    for fold in &manual.folds {
+        paper.fold(fold);
+        match *fold {
+            Fold::X(v) => visible.max.x = v - 1,
+            Fold::Y(v) => visible.max.y = v - 1,
+        }
+        frames.push(&paper.frame(bounds, visible))?;
+    }
+    Ok(frames.count())
+}
+
+/// Folds the paper one instruction at a time, for `aoc repl`.
+struct Folding {
+    manual: Manual,
+    folded: usize,
+}
+
+impl Simulation for Folding {
+    fn step(&mut self) -> Option<String> {
+        let fold = self.manual.folds.get(self.folded)?;
+        self.manual.paper.fold(fold);
+        self.folded += 1;
+        Some(format!("{}, {} dots", fold, self.manual.paper.dots.len()))
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["dots", ..] => Ok(self.manual.paper.dots.len() as u64 <= condition_count(condition)?),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[("dots N", "at most N dots left")]
+    }
+}
+
+impl fmt::Display for Folding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.manual.paper)
+    }
+}
+
+/// The paper of `lines`, folded fold by fold.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    Ok(Box::new(Folding {
+        manual: Day13::parse(lines)?,
+        folded: 0,
+    }))
+}
+
+/// Part 1 counts the dots after the first fold, part 2 draws the code.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+
+    fn parse(lines: &[String]) -> Result<Manual, AocError> {
+        let mut manual = Manual::new();
+        let mut line_iter = lines.iter().enumerate();
+        for (i, line) in &mut line_iter {
+            if line.is_empty() {
+                break;
+            }
+            manual
+                .paper
+                .dots
+                .insert(parse_dot(line).map_err(|e| e.at_line(i))?);
+        }
+        for (i, line) in &mut line_iter {
+            manual
+                .folds
+                .push(line.parse().map_err(|e: AocError| e.at_line(i))?);
+        }
+        if manual.folds.is_empty() {
+            return Err(AocError::new("no fold instructions"));
+        }
+        Ok(manual)
+    }
+
+    fn part1(manual: &Manual) -> Result<Answer, AocError> {
+        let mut paper = manual.paper.clone();
+        paper.fold(&manual.folds[0]);
+        Ok(paper.dots.len().into())
+    }
+
+    fn part2(manual: &Manual) -> Result<Answer, AocError> {
+        let mut paper = manual.paper.clone();
+        for fold in &manual.folds {
+            paper.fold(fold);
+        }
+        Ok(Answer::Art(paper.to_string().trim_end().to_string()))
+    }
+}
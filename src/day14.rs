@@ -0,0 +1,167 @@
+//! Day 14: Extended Polymerization, pair insertion rules.
+
+use crate::error::{first_line, AocError};
+use crate::repl::{condition_count, Simulation};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Manual {
+    polymer_template: String,
+    pair_insertions: HashMap<(char, char), char>,
+    current_pairs_count: HashMap<(char, char), u64>,
+}
+
+impl Manual {
+    pub fn template(&self) -> &str {
+        &self.polymer_template
+    }
+    pub fn rules(&self) -> &HashMap<(char, char), char> {
+        &self.pair_insertions
+    }
+    fn new(lines: &[String]) -> Result<Manual, AocError> {
+        let polymer_template = first_line(lines)?.to_string();
+        if polymer_template.is_empty() {
+            return Err(AocError::new("empty polymer template").at_line(0));
+        }
+        if lines.get(1).is_some_and(|s| !s.is_empty()) {
+            return Err(AocError::new("expected an empty line").at_line(1));
+        }
+        let mut pair_insertions = HashMap::new();
+        let mut current_pairs_count = HashMap::new();
+        for pair in polymer_template.chars().collect::<Vec<char>>().windows(2) {
+            *current_pairs_count.entry((pair[0], pair[1])).or_insert(0) += 1
+        }
+        for (i, pair_insertion) in lines.iter().enumerate().skip(2) {
+            let rule = pair_insertion.split(" -> ").collect::<Vec<&str>>();
+            let pair = rule[0].chars().collect::<Vec<char>>();
+            let element = rule.get(1).map(|s| s.chars().collect::<Vec<char>>());
+            match (pair.as_slice(), element.as_deref()) {
+                ([a, b], Some([c])) => pair_insertions.insert((*a, *b), *c),
+                _ => return Err(AocError::new("expected `AB -> C`").at_line(i)),
+            };
+        }
+        Ok(Manual {
+            polymer_template,
+            pair_insertions,
+            current_pairs_count,
+        })
+    }
+    fn step(&mut self) {
+        let mut new_pairs = HashMap::new();
+        for (pair, count) in self.current_pairs_count.iter() {
+            if let Some(ch) = self.pair_insertions.get(pair) {
+                *new_pairs.entry((pair.0, *ch)).or_insert(0) += count;
+                *new_pairs.entry((*ch, pair.1)).or_insert(0) += count;
+            } else {
+                *new_pairs.entry(*pair).or_insert(0) += count;
+            }
+        }
+        self.current_pairs_count = new_pairs;
+    }
+    /// Number of elements in the polymer.
+    fn len(&self) -> u64 {
+        self.current_pairs_count.values().sum::<u64>() + 1
+    }
+    fn char_count(&self) -> HashMap<char, u64> {
+        let mut res = HashMap::new();
+        for (pair, count) in self.current_pairs_count.iter() {
+            *res.entry(pair.0).or_insert(0) += count;
+            *res.entry(pair.1).or_insert(0) += count;
+        }
+        // Every element is in two pairs but the ends, which are in one.
+        let template = &self.polymer_template;
+        *res.entry(template.chars().next().unwrap()).or_insert(0) += 1;
+        *res.entry(template.chars().last().unwrap()).or_insert(0) += 1;
+        for (_, val) in res.iter_mut() {
+            *val /= 2;
+        }
+        res
+    }
+}
+
+impl fmt::Display for Manual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} elements grown from {}",
+            self.len(),
+            self.polymer_template
+        )?;
+        let mut counts: Vec<_> = self.char_count().into_iter().collect();
+        counts.sort();
+        for (element, count) in counts {
+            writeln!(f, "{}: {}", element, count)?;
+        }
+        write!(f, "")
+    }
+}
+
+/// How many times each element occurs after `steps` steps, counting pairs
+/// rather than building the polymer.
+pub fn element_counts(manual: &Manual, steps: u32) -> HashMap<char, u64> {
+    let mut manual = manual.clone();
+    for _ in 0..steps {
+        manual.step();
+    }
+    manual.char_count()
+}
+
+fn most_minus_least_common(manual: &Manual, steps: u32) -> u64 {
+    let chars = element_counts(manual, steps);
+    let max = chars.values().max().unwrap();
+    let min = chars.values().min().unwrap();
+    max - min
+}
+
+/// Inserts elements step by step for `aoc repl`.
+impl Simulation for Manual {
+    fn step(&mut self) -> Option<String> {
+        // The polymer at most doubles in length, stop before the counts overflow.
+        if self.len() > u64::MAX / 2 {
+            return None;
+        }
+        Manual::step(self);
+        Some(format!(
+            "{} elements, most minus least common {}",
+            self.len(),
+            most_minus_least_common(self, 0)
+        ))
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["length", ..] => Ok(self.len() >= condition_count(condition)?),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[("length N", "the polymer has at least N elements")]
+    }
+}
+
+/// The polymer of `lines`, step by step.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    Ok(Box::new(Manual::new(lines)?))
+}
+
+/// Most minus least common element after 10 and 40 steps.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Manual;
+
+    fn parse(lines: &[String]) -> Result<Manual, AocError> {
+        Manual::new(lines)
+    }
+
+    fn part1(manual: &Manual) -> Result<Answer, AocError> {
+        Ok(most_minus_least_common(manual, 10).into())
+    }
+
+    fn part2(manual: &Manual) -> Result<Answer, AocError> {
+        Ok(most_minus_least_common(manual, 40).into())
+    }
+}
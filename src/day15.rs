@@ -0,0 +1,111 @@
+//! Day 15: Chiton, the path of lowest risk.
+
+use crate::error::AocError;
+use crate::frames::{Frame, Frames};
+use crate::grid::Grid;
+use crate::point::Point2;
+use crate::search::{self, SearchProblem, SearchResult};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct Graph {
+    weights: Grid<u32>,
+}
+
+impl Graph {
+    fn new(lines: &[String]) -> Result<Graph, AocError> {
+        Ok(Graph {
+            weights: Grid::parse_digits(lines)?,
+        })
+    }
+    /// The map repeated `times` times in both directions, each tile one
+    /// riskier than the one to the left or above, wrapping 9 back to 1.
+    fn tiled(&self, times: usize) -> Grid<u32> {
+        let (width, height) = (self.weights.width(), self.weights.height());
+        Grid::from_fn(width * times, height * times, |x, y| {
+            let w = self.weights[(x % width, y % height)] + (x / width + y / height) as u32;
+            w % 10 + (w / 10)
+        })
+    }
+}
+
+/// Path from the top left to the bottom right corner of the cavern.
+struct Route<'a> {
+    weights: &'a Grid<u32>,
+    goal: Point2,
+}
+
+impl SearchProblem for Route<'_> {
+    type State = Point2;
+
+    fn start(&self) -> Point2 {
+        Point2::ORIGIN
+    }
+
+    fn is_goal(&self, state: &Point2) -> bool {
+        *state == self.goal
+    }
+
+    fn successors(&self, state: &Point2) -> Vec<(Point2, u64)> {
+        state
+            .neighbors4()
+            .filter_map(|next| Some((next, *self.weights.get_point(next)? as u64)))
+            .collect()
+    }
+
+    fn heuristic(&self, state: &Point2) -> u64 {
+        state.manhattan(self.goal) as u64
+    }
+}
+
+fn safest_route(weights: &Grid<u32>) -> SearchResult<Point2> {
+    let goal = Point2::from((weights.width() - 1, weights.height() - 1));
+    let route = Route { weights, goal };
+    search::a_star(&route).unwrap()
+}
+
+fn lowest_total_risk(graph: &Graph, modificator: usize) -> u64 {
+    safest_route(&graph.tiled(modificator)).cost
+}
+
+/// Writes the map and the map tiled five times with their safest routes in
+/// red, darker cells being riskier. Returns the number of frames.
+pub fn frames(lines: &[String], dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let graph = Graph::new(lines)?;
+    let mut frames = Frames::new(dir, "day15")?;
+    for times in [1, 5] {
+        let weights = graph.tiled(times);
+        let route: HashSet<_> = safest_route(&weights).path.into_iter().collect();
+        frames.push(&Frame::color(&weights, |pos, &risk| {
+            if route.contains(&Point2::from(pos)) {
+                [255, 40, 40]
+            } else {
+                let shade = 255 - risk as u8 * 25;
+                [shade, shade, shade]
+            }
+        }))?;
+    }
+    Ok(frames.count())
+}
+
+/// Lowest total risk across the map and across the map tiled five times.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Graph;
+
+    fn parse(lines: &[String]) -> Result<Graph, AocError> {
+        Graph::new(lines)
+    }
+
+    fn part1(graph: &Graph) -> Result<Answer, AocError> {
+        Ok(lowest_total_risk(graph, 1).into())
+    }
+
+    fn part2(graph: &Graph) -> Result<Answer, AocError> {
+        Ok(lowest_total_risk(graph, 5).into())
+    }
+}
@@ -0,0 +1,189 @@
+//! Day 16: Packet Decoder, parsing BITS transmissions.
+
+use crate::bits::BitReader;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+/// A decoded packet with its sub-packets.
+#[derive(Debug)]
+pub struct Package {
+    version: usize,
+    package_type: usize,
+    packages: Vec<Package>,
+    value: Option<usize>,
+}
+
+impl Package {
+    pub fn version(&self) -> usize {
+        self.version
+    }
+    /// 4 for literal values, one of the operators otherwise.
+    pub fn package_type(&self) -> usize {
+        self.package_type
+    }
+    pub fn packages(&self) -> &[Package] {
+        &self.packages
+    }
+    /// The number of a literal value packet.
+    pub fn value(&self) -> Option<usize> {
+        self.value
+    }
+    /// Evaluates the expression the packet encodes.
+    pub fn calculate(&self) -> usize {
+        match self.package_type {
+            0 => {
+                let mut s = 0;
+                for p in &self.packages {
+                    s += p.calculate();
+                }
+                s
+            }
+            1 => {
+                let mut s = 1;
+                for p in &self.packages {
+                    s *= p.calculate();
+                }
+                s
+            }
+            2 => self.packages.iter().map(|p| p.calculate()).min().unwrap(),
+            3 => self.packages.iter().map(|p| p.calculate()).max().unwrap(),
+            4 => self.value.unwrap(),
+            5 => (self.packages[0].calculate() > self.packages[1].calculate()) as usize,
+            6 => (self.packages[0].calculate() < self.packages[1].calculate()) as usize,
+            7 => (self.packages[0].calculate() == self.packages[1].calculate()) as usize,
+            _ => 999,
+        }
+    }
+}
+
+/// A hexadecimal transmission, decoded packet by packet.
+#[derive(Debug)]
+pub struct Transmission {
+    bytes: Vec<u8>,
+}
+
+impl Transmission {
+    pub fn new(s: &str) -> Result<Transmission, AocError> {
+        let bytes = hex::decode(s).map_err(|e| match e {
+            hex::FromHexError::InvalidHexCharacter { c, index } => {
+                AocError::new(format!("invalid hex character {:?}", c)).at_column(index)
+            }
+            e => AocError::new(e.to_string()),
+        })?;
+        Ok(Transmission { bytes })
+    }
+    /// Decodes the outermost packet.
+    pub fn decode(&self) -> Result<Package, AocError> {
+        parse_package(&mut BitReader::new(&self.bytes))
+    }
+}
+
+/// Error at the hex digit holding the bit at `pos`.
+fn error_at(pos: usize, reason: &str) -> AocError {
+    AocError::new(reason).at_column(pos / 4)
+}
+
+fn read(bits: &mut BitReader, width: usize) -> Result<usize, AocError> {
+    bits.read(width)
+        .map(|v| v as usize)
+        .map_err(|e| error_at(e.position, "unexpected end of transmission"))
+}
+
+fn parse_literal_value(bits: &mut BitReader) -> Result<usize, AocError> {
+    let mut value = 0;
+    loop {
+        value <<= 4;
+        let part = read(bits, 5)?;
+        value += part & 15;
+        if (part & 16) == 0 {
+            break Ok(value);
+        }
+    }
+}
+
+fn parse_sub_packages(bits: &mut BitReader) -> Result<Vec<Package>, AocError> {
+    let length_type_id = read(bits, 1)?;
+    let mut res = Vec::new();
+    if length_type_id == 0 {
+        let length_in_bits = read(bits, 15)?;
+        let cur = bits.position();
+        while bits.position() < cur + length_in_bits {
+            res.push(parse_package(bits)?);
+        }
+    } else {
+        let number = read(bits, 11)?;
+        for _ in 0..number {
+            res.push(parse_package(bits)?);
+        }
+    }
+    Ok(res)
+}
+
+/// Decodes the packet `bits` are at, leaving them past the packet.
+pub fn parse_package(bits: &mut BitReader) -> Result<Package, AocError> {
+    let start = bits.position();
+    let version = read(bits, 3)?;
+    let package_type = read(bits, 3)?;
+    let mut packages = Vec::new();
+    let mut value = None;
+
+    if package_type == 4 {
+        value = Some(parse_literal_value(bits)?);
+    } else {
+        packages = parse_sub_packages(bits)?;
+    }
+    match (package_type, packages.len()) {
+        (0..=3, 0) => return Err(error_at(start, "operator without sub-packets")),
+        (5..=7, n) if n != 2 => {
+            return Err(error_at(start, "comparison needs exactly two sub-packets"))
+        }
+        _ => (),
+    }
+
+    Ok(Package {
+        version,
+        package_type,
+        packages,
+        value,
+    })
+}
+
+/// Sum of the versions of `package` and all its sub-packets.
+pub fn sum_versions(package: &Package) -> usize {
+    let mut res = package.version;
+    for sub_package in &package.packages {
+        res += sum_versions(sub_package);
+    }
+    res
+}
+
+/// Part 1 sums the versions of each transmission, part 2 evaluates it.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Package>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Package>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                Transmission::new(line)
+                    .and_then(|t| t.decode())
+                    .map_err(|e| e.at_line(i))
+            })
+            .collect()
+    }
+
+    fn part1(packages: &Vec<Package>) -> Result<Answer, AocError> {
+        Ok(Answer::Ints(
+            packages.iter().map(|p| sum_versions(p) as i64).collect(),
+        ))
+    }
+
+    fn part2(packages: &Vec<Package>) -> Result<Answer, AocError> {
+        Ok(Answer::Ints(
+            packages.iter().map(|p| p.calculate() as i64).collect(),
+        ))
+    }
+}
@@ -0,0 +1,97 @@
+//! Day 17: Trick Shot, launching a probe into the target area.
+
+use crate::error::{parse_at, parse_lines, AocError};
+use crate::solution::{Answer, Solution};
+use rayon::prelude::*;
+use regex::Regex;
+use std::str::FromStr;
+
+/// Part 1 is the highest reachable y, part 2 the number of velocities hitting the target.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Area>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Area>, AocError> {
+        parse_lines(lines)
+    }
+
+    fn part1(areas: &Vec<Area>) -> Result<Answer, AocError> {
+        Ok(Answer::Ints(
+            areas.iter().map(|a| highest_y(a) as i64).collect(),
+        ))
+    }
+
+    fn part2(areas: &Vec<Area>) -> Result<Answer, AocError> {
+        Ok(Answer::Ints(
+            areas.iter().map(|a| count_velocities(a) as i64).collect(),
+        ))
+    }
+}
+
+fn highest_y(area: &Area) -> i32 {
+    let v = -(area.y1 + 1);
+    v * (v + 1) / 2
+}
+
+pub struct Area {
+    x1: i32,
+    x2: i32,
+    y1: i32,
+    y2: i32,
+}
+
+impl Area {
+    fn new(x1: i32, x2: i32, y1: i32, y2: i32) -> Area {
+        Area { x1, x2, y1, y2 }
+    }
+    fn check(&self, mut x: i32, mut y: i32) -> bool {
+        let mut cur_x = 0;
+        let mut cur_y = 0;
+        loop {
+            cur_x += x;
+            cur_y += y;
+            if cur_x >= self.x1 && cur_x <= self.x2 && cur_y >= self.y1 && cur_y <= self.y2 {
+                return true;
+            }
+            if cur_x > self.x2 || cur_y < self.y1 {
+                break;
+            }
+            if x > 0 {
+                x -= 1;
+            }
+            y -= 1;
+        }
+        false
+    }
+}
+
+impl FromStr for Area {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"x=(.*)\.\.(.*), y=(.*)\.\.(.*)").unwrap();
+        let caps = re
+            .captures(s)
+            .ok_or_else(|| AocError::new("expected `target area: x=A..B, y=C..D`"))?;
+        let field = |i| parse_at(s, caps.get(i).unwrap().as_str());
+        let area = Area::new(field(1)?, field(2)?, field(3)?, field(4)?);
+        // Both solutions rely on the area being to the right of and below the launcher.
+        if area.x1 <= 0 || area.x1 > area.x2 {
+            return Err(AocError::new("target must be right of the launcher")
+                .at(s, caps.get(1).unwrap().as_str()));
+        }
+        if area.y2 >= -1 || area.y1 > area.y2 {
+            return Err(AocError::new("target must be below the launcher")
+                .at(s, caps.get(3).unwrap().as_str()));
+        }
+        Ok(area)
+    }
+}
+
+fn count_velocities(area: &Area) -> i32 {
+    (0..area.x2 + 1)
+        .into_par_iter()
+        .map(|x| (area.y1..-area.y1).filter(|&y| area.check(x, y)).count() as i32)
+        .sum()
+}
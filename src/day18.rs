@@ -0,0 +1,227 @@
+//! Day 18: Snailfish, adding and reducing snailfish numbers.
+
+use crate::error::{parse_lines, AocError};
+use crate::solution::{Answer, Solution};
+use rayon::prelude::*;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+struct PsevdoItem {
+    value: u32,
+    level: u32,
+}
+
+impl ops::Add<&Pair> for &Pair {
+    type Output = Pair;
+
+    fn add(self, rhs: &Pair) -> Pair {
+        let mut items = Vec::with_capacity(self.items.len() + rhs.items.len());
+        for i in self.items.iter().chain(rhs.items.iter()) {
+            items.push(PsevdoItem {
+                value: i.value,
+                level: i.level + 1,
+            });
+        }
+        let mut p = Pair { items };
+        p.reduce();
+        p
+    }
+}
+
+/// A snailfish number, stored as its regular numbers in order together with
+/// how deeply each one is nested. Add two of them with `&a + &b`.
+#[derive(Debug, Clone)]
+pub struct Pair {
+    items: Vec<PsevdoItem>,
+}
+impl Pair {
+    pub fn magnitude(&self) -> u32 {
+        fn rec(items: &Vec<PsevdoItem>, index: &mut usize, level: u32) -> u32 {
+            let mut res = 0;
+            let v = &items[*index];
+            if v.level == level {
+                res += v.value * 3;
+            } else {
+                res += rec(items, index, level + 1) * 3;
+            }
+            *index += 1;
+            let v = &items[*index];
+            if v.level == level {
+                res += v.value * 2;
+            } else {
+                res += rec(items, index, level + 1) * 2;
+            }
+            res
+        }
+        rec(&self.items, &mut 0, 1)
+    }
+
+    /// Explodes and splits until no more action applies.
+    pub fn reduce(&mut self) {
+        loop {
+            let mut explode = None;
+            for i in 0..self.items.len() {
+                if self.items[i].level >= 5 {
+                    assert!(self.items[i + 1].level >= 5, "something wrong");
+                    explode = Some(i);
+                    break;
+                }
+            }
+            if let Some(explode) = explode {
+                if explode > 0 {
+                    self.items[explode - 1].value += self.items[explode].value;
+                }
+                if explode + 2 < self.items.len() {
+                    self.items[explode + 2].value += self.items[explode + 1].value;
+                }
+                self.items.remove(explode);
+                self.items[explode] = PsevdoItem { value: 0, level: 4 };
+                continue;
+            }
+
+            let mut split = None;
+            for i in 0..self.items.len() {
+                if self.items[i].value >= 10 {
+                    split = Some(i);
+                    break;
+                }
+            }
+            if let Some(split) = split {
+                let v = self.items[split].value;
+                let lvl = self.items[split].level;
+                self.items[split].value = v / 2;
+                self.items[split].level += 1;
+                self.items.insert(
+                    split + 1,
+                    PsevdoItem {
+                        value: v / 2 + v % 2,
+                        level: lvl + 1,
+                    },
+                );
+                continue;
+            }
+            break;
+        }
+    }
+}
+
+impl FromStr for Pair {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn expect(s: &[u8], pos: &mut usize, c: u8) -> Result<(), AocError> {
+            if s.get(*pos) != Some(&c) {
+                return Err(AocError::new(format!("expected {:?}", c as char)).at_column(*pos));
+            }
+            *pos += 1;
+            Ok(())
+        }
+        fn element(
+            s: &[u8],
+            pos: &mut usize,
+            level: u32,
+            items: &mut Vec<PsevdoItem>,
+        ) -> Result<(), AocError> {
+            match s.get(*pos) {
+                Some(b'[') => pair(s, pos, level + 1, items),
+                Some(c @ b'0'..=b'9') => {
+                    items.push(PsevdoItem {
+                        value: (c - b'0') as u32,
+                        level,
+                    });
+                    *pos += 1;
+                    Ok(())
+                }
+                _ => Err(AocError::new("expected digit or '['").at_column(*pos)),
+            }
+        }
+        fn pair(
+            s: &[u8],
+            pos: &mut usize,
+            level: u32,
+            items: &mut Vec<PsevdoItem>,
+        ) -> Result<(), AocError> {
+            expect(s, pos, b'[')?;
+            element(s, pos, level, items)?;
+            expect(s, pos, b',')?;
+            element(s, pos, level, items)?;
+            expect(s, pos, b']')
+        }
+
+        let mut items = Vec::new();
+        let mut pos = 0;
+        pair(s.as_bytes(), &mut pos, 1, &mut items)?;
+        if pos != s.len() {
+            return Err(AocError::new("unexpected trailing characters").at_column(pos));
+        }
+        Ok(Pair { items })
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn rec(
+            items: &Vec<PsevdoItem>,
+            index: &mut usize,
+            level: u32,
+            f: &mut fmt::Formatter,
+        ) -> fmt::Result {
+            write!(f, "[")?;
+            let v = &items[*index];
+            if v.level == level {
+                write!(f, "{}", v.value)?;
+            } else {
+                rec(items, index, level + 1, f)?;
+            }
+            write!(f, ",")?;
+            *index += 1;
+            let v = &items[*index];
+            if v.level == level {
+                write!(f, "{}", v.value)?;
+            } else {
+                rec(items, index, level + 1, f)?;
+            }
+            write!(f, "]")
+        }
+        rec(&self.items, &mut 0, 1, f)
+    }
+}
+
+/// Part 1 is the magnitude of the sum, part 2 the largest magnitude of any two numbers.
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Pair>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Pair>, AocError> {
+        let pairs = parse_lines(lines)?;
+        if pairs.len() < 2 {
+            return Err(AocError::new("expected at least two numbers"));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> Result<Answer, AocError> {
+        let mut res = pairs[0].clone();
+        for pair in &pairs[1..] {
+            res = &res + pair;
+        }
+        Ok(res.magnitude().into())
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> Result<Answer, AocError> {
+        let n = pairs.len();
+        Ok((0..n)
+            .into_par_iter()
+            .flat_map_iter(|i| {
+                (0..n)
+                    .filter(move |&j| j != i)
+                    .map(move |j| (&pairs[i] + &pairs[j]).magnitude())
+            })
+            .max()
+            .unwrap()
+            .into())
+    }
+}
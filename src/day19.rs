@@ -0,0 +1,185 @@
+//! Day 19: Beacon Scanner, assembling the map of beacons.
+
+use crate::error::{parse_at, AocError};
+use crate::point::{parse_point3, Point3, Rotation};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+
+const THRESHOLD: u32 = 12;
+
+#[derive(Debug)]
+pub struct Scanner {
+    _id: i32,
+    beacons: Vec<Point3>,
+    position: Option<Point3>,
+    distances: Vec<i64>,
+}
+
+fn manhattan_distances(beacons: &[Point3]) -> Vec<i64> {
+    let mut res = Vec::new();
+    for i in 0..beacons.len() - 1 {
+        for j in i + 1..beacons.len() {
+            res.push(beacons[i].manhattan(beacons[j]));
+        }
+    }
+    res.sort();
+    res
+}
+
+fn fast_check(scanners: &[Scanner], s1: usize, s2: usize) -> bool {
+    let d1 = &scanners[s1].distances;
+    let d2 = &scanners[s2].distances;
+    let mut count = 0;
+    let (mut i1, mut i2) = (0, 0);
+    loop {
+        if i1 >= d1.len() || i2 >= d2.len() {
+            break;
+        }
+        if d1[i1] == d2[i2] {
+            count += 1;
+            i1 += 1;
+            i2 += 1;
+        } else if d1[i1] > d2[i2] {
+            i2 += 1;
+        } else {
+            i1 += 1;
+        }
+    }
+    count >= (THRESHOLD * (THRESHOLD - 1)) / 2
+}
+
+fn parse_scanners(lines: &[String]) -> Result<Vec<Scanner>, AocError> {
+    let re = Regex::new(r"^--- scanner (\d+) ---$").unwrap();
+    let mut res = Vec::new();
+    let mut id = 0;
+    let mut beacons = Vec::new();
+    let mut push_scanner = |id, beacons: &mut Vec<Point3>, i| {
+        if beacons.len() < THRESHOLD as usize {
+            return Err(AocError::new(format!(
+                "scanner {} sees less than {} beacons",
+                id, THRESHOLD
+            ))
+            .at_line(i));
+        }
+        res.push(Scanner {
+            _id: id,
+            position: None,
+            beacons: beacons.clone(),
+            distances: manhattan_distances(beacons),
+        });
+        beacons.clear();
+        Ok(())
+    };
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            push_scanner(id, &mut beacons, i)?;
+            continue;
+        }
+        if line.contains("---") {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| AocError::new("expected `--- scanner <n> ---`").at_line(i))?;
+            id = parse_at(line, caps.get(1).unwrap().as_str()).map_err(|e| e.at_line(i))?;
+            continue;
+        }
+        beacons.push(parse_point3(line, line).map_err(|e| e.at_line(i))?);
+    }
+    if !beacons.is_empty() {
+        push_scanner(id, &mut beacons, lines.len())?;
+    }
+    Ok(res)
+}
+
+fn coordinate_scanners(scanners: &mut [Scanner]) {
+    let mut queue = Vec::new();
+    queue.push(0);
+    while let Some(s1) = queue.pop() {
+        for s2 in 0..scanners.len() {
+            if scanners[s2].position.is_none() && coordinate_scanner(scanners, s1, s2) {
+                queue.push(s2);
+            }
+        }
+    }
+}
+
+/// Part 1 counts the beacons, part 2 is the largest distance between two scanners.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Scanner>, AocError> {
+        let mut scanners = parse_scanners(lines)?;
+        if scanners.len() < 2 {
+            return Err(AocError::new("expected at least two scanners"));
+        }
+        scanners[0].position = Some(Point3::ORIGIN);
+
+        coordinate_scanners(&mut scanners);
+        if let Some(s) = scanners.iter().find(|s| s.position.is_none()) {
+            return Err(AocError::new(format!("can't locate scanner {}", s._id)));
+        }
+        Ok(scanners)
+    }
+
+    fn part1(scanners: &Vec<Scanner>) -> Result<Answer, AocError> {
+        let beacons: HashSet<Point3> = scanners
+            .iter()
+            .flat_map(|s| s.beacons.iter().copied())
+            .collect();
+        Ok(beacons.len().into())
+    }
+
+    fn part2(scanners: &Vec<Scanner>) -> Result<Answer, AocError> {
+        let mut max_d = 0;
+        for i in 0..scanners.len() - 1 {
+            for j in i + 1..scanners.len() {
+                let d = scanners[i]
+                    .position
+                    .unwrap()
+                    .manhattan(scanners[j].position.unwrap());
+                if d > max_d {
+                    max_d = d;
+                }
+            }
+        }
+        Ok(max_d.into())
+    }
+}
+
+fn check_common(a: &[Point3], b: &[Point3]) -> u32 {
+    let mut res = 0;
+    for i in a {
+        res += b.iter().filter(|&j| i == j).count() as u32;
+    }
+    res
+}
+
+fn coordinate_scanner(scanners: &mut [Scanner], s1: usize, s2: usize) -> bool {
+    assert!(scanners[s1].position.is_some(), "s1 has unknown position");
+    if !fast_check(scanners, s1, s2) {
+        return false;
+    }
+    let rotations = Rotation::all();
+    for i in 0..(scanners[s1].beacons.len() - THRESHOLD as usize + 1) {
+        for j in 0..scanners[s2].beacons.len() {
+            for rotation in &rotations {
+                let b1 = scanners[s1].beacons[i];
+                let b2 = scanners[s2].beacons[j];
+                let shift = rotation.apply(b2) - b1;
+                let beacons: Vec<Point3> = scanners[s2]
+                    .beacons
+                    .iter()
+                    .map(|&b| rotation.apply(b) - shift)
+                    .collect();
+                if check_common(&scanners[s1].beacons, &beacons) >= THRESHOLD {
+                    scanners[s2].position = Some(-shift);
+                    scanners[s2].beacons = beacons;
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
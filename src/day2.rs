@@ -0,0 +1,247 @@
+//! Day 2: Dive!, steering the submarine.
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+
+/// A move of the submarine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl Move {
+    /// The move cancelling this one.
+    fn inverse(self) -> Move {
+        match self {
+            Move::Forward(x) => Move::Forward(-x),
+            Move::Down(x) => Move::Up(x),
+            Move::Up(x) => Move::Down(x),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Move::Forward(x) => write!(f, "forward {}", x),
+            Move::Down(x) => write!(f, "down {}", x),
+            Move::Up(x) => write!(f, "up {}", x),
+        }
+    }
+}
+
+/// A line of the course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Move(Move),
+    /// Replays the last moves backwards, undoing them: all of the moves not
+    /// undone yet, or only the given number of them.
+    Back(Option<usize>),
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Command, AocError> {
+        let mut words = line.split(' ');
+        let direction = words.next().unwrap();
+        let units = words.next();
+        if words.next().is_some() {
+            return Err(AocError::new("expected `<direction> <units>`"));
+        }
+        if let "back" | "reverse" = direction {
+            return Ok(Command::Back(units.map(|n| parse_at(line, n)).transpose()?));
+        }
+        let units = units.ok_or_else(|| AocError::new("expected `<direction> <units>`"))?;
+        let units = parse_at(line, units)?;
+        match direction {
+            "forward" => Ok(Command::Move(Move::Forward(units))),
+            "down" => Ok(Command::Move(Move::Down(units))),
+            "up" => Ok(Command::Move(Move::Up(units))),
+            _ => {
+                Err(AocError::new(format!("unknown direction {:?}", direction)).at(line, direction))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Command::Move(m) => write!(f, "{}", m),
+            Command::Back(None) => write!(f, "back"),
+            Command::Back(Some(n)) => write!(f, "back {}", n),
+        }
+    }
+}
+
+/// What a submarine makes of the moves.
+pub trait Submarine {
+    fn forward(&mut self, units: i32);
+    /// Going up is going down by minus as many units.
+    fn down(&mut self, units: i32);
+    fn horizontal_position(&self) -> i32;
+    fn depth(&self) -> i32;
+    /// `None` for submarines without one.
+    fn aim(&self) -> Option<i32> {
+        None
+    }
+}
+
+/// Part 1 submarine, `down` and `up` change the depth.
+#[derive(Debug, Clone, Default)]
+pub struct SimpleSubmarine {
+    horizontal_position: i32,
+    depth: i32,
+}
+
+impl Submarine for SimpleSubmarine {
+    fn forward(&mut self, units: i32) {
+        self.horizontal_position += units;
+    }
+    fn down(&mut self, units: i32) {
+        self.depth += units;
+    }
+    fn horizontal_position(&self) -> i32 {
+        self.horizontal_position
+    }
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+}
+
+/// Part 2 submarine, `down` and `up` change the aim, which `forward` follows.
+#[derive(Debug, Clone, Default)]
+pub struct AimingSubmarine {
+    horizontal_position: i32,
+    depth: i32,
+    aim: i32,
+}
+
+impl Submarine for AimingSubmarine {
+    fn forward(&mut self, units: i32) {
+        self.horizontal_position += units;
+        self.depth += units * self.aim;
+    }
+    fn down(&mut self, units: i32) {
+        self.aim += units;
+    }
+    fn horizontal_position(&self) -> i32 {
+        self.horizontal_position
+    }
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+    fn aim(&self) -> Option<i32> {
+        Some(self.aim)
+    }
+}
+
+/// `position 15, depth 10, aim 10`, without the aim for submarines without one.
+pub fn describe(submarine: &dyn Submarine) -> String {
+    let mut res = format!(
+        "position {}, depth {}",
+        submarine.horizontal_position(),
+        submarine.depth()
+    );
+    if let Some(aim) = submarine.aim() {
+        res += &format!(", aim {}", aim);
+    }
+    res
+}
+
+/// One move of the submarine, traced by [`navigate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The move on the given line, counted from 0.
+    Moved(usize, Move),
+    /// The move on the given line, undone by a `back` on another one.
+    Undid(usize, Move),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Moved(line, m) => write!(f, "line {}: {}", line + 1, m),
+            Step::Undid(line, m) => write!(f, "undo line {}: {}", line + 1, m),
+        }
+    }
+}
+
+fn steer(submarine: &mut dyn Submarine, m: Move) {
+    match m {
+        Move::Forward(x) => submarine.forward(x),
+        Move::Down(x) => submarine.down(x),
+        Move::Up(x) => submarine.down(-x),
+    }
+}
+
+/// Follows `commands`, calling `after` with every move and the submarine
+/// after it. Going back more moves than there are goes back to the start.
+pub fn navigate<S, F>(submarine: &mut S, commands: &[Command], mut after: F)
+where
+    S: Submarine,
+    F: FnMut(Step, &S),
+{
+    // Moves not undone yet, with their lines.
+    let mut course: Vec<(usize, Move)> = Vec::new();
+    for (line, &command) in commands.iter().enumerate() {
+        match command {
+            Command::Back(n) => {
+                let n = n.unwrap_or(course.len()).min(course.len());
+                for (line, m) in course.split_off(course.len() - n).into_iter().rev() {
+                    steer(submarine, m.inverse());
+                    after(Step::Undid(line, m), submarine);
+                }
+            }
+            Command::Move(m) => {
+                steer(submarine, m);
+                course.push((line, m));
+                after(Step::Moved(line, m), submarine);
+            }
+        }
+    }
+}
+
+fn final_product<S: Submarine + Default>(commands: &[Command]) -> i32 {
+    let mut submarine = S::default();
+    navigate(&mut submarine, commands, |_, _| ());
+    submarine.depth() * submarine.horizontal_position()
+}
+
+/// Part 1 follows the commands literally, part 2 with `down`/`up` changing the aim.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Command>, AocError> {
+        let mut commands = Vec::with_capacity(lines.len());
+        let mut moves = 0;
+        for (i, line) in lines.iter().enumerate() {
+            let command = Command::parse(line).map_err(|e| e.at_line(i))?;
+            match command {
+                Command::Back(Some(n)) if n > moves => {
+                    return Err(AocError::new(format!(
+                        "can't go back {} moves, there are only {}",
+                        n, moves
+                    ))
+                    .at_line(i))
+                }
+                Command::Back(n) => moves -= n.unwrap_or(moves),
+                _ => moves += 1,
+            }
+            commands.push(command);
+        }
+        Ok(commands)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<Answer, AocError> {
+        Ok(final_product::<SimpleSubmarine>(commands).into())
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<Answer, AocError> {
+        Ok(final_product::<AimingSubmarine>(commands).into())
+    }
+}
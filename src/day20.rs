@@ -0,0 +1,201 @@
+//! Day 20: Trench Map, enhancing an infinite image.
+
+use crate::error::{first_line, AocError};
+use crate::frames::{Frame, Frames};
+use crate::grid::Grid;
+use crate::point::Point2;
+use crate::repl::{condition_count, Simulation};
+use crate::solution::{Answer, Solution};
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+/// Enhancements of part 2.
+const STEPS: usize = 50;
+
+#[derive(Debug, Clone)]
+struct Image {
+    pixels: Grid<bool>, // true -- light
+    other_pixels: bool,
+}
+
+impl Image {
+    fn new(pixels: Grid<bool>) -> Image {
+        Image {
+            pixels,
+            other_pixels: false,
+        }
+    }
+    fn is_light(&self, p: Point2) -> bool {
+        *self.pixels.get_point(p).unwrap_or(&self.other_pixels)
+    }
+    /// The enhanced image grows by one pixel on each side, everything
+    /// further out is decided by `other_pixels`.
+    fn enhance(&self, algorithm: &[bool; 512]) -> Image {
+        let pixels = Grid::from_fn(self.pixels.width() + 2, self.pixels.height() + 2, |x, y| {
+            let p = Point2::from((x, y)) - Point2::new(1, 1);
+            let mut i = 0;
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    i = i << 1 | self.is_light(p + Point2::new(dx, dy)) as usize;
+                }
+            }
+            algorithm[i]
+        });
+        let other_pixels = algorithm[if self.other_pixels { 511 } else { 0 }];
+
+        Image {
+            pixels,
+            other_pixels,
+        }
+    }
+    fn count_light(&self) -> usize {
+        self.pixels.values().filter(|&&p| p).count()
+    }
+    /// The image with `margin` more pixels of the infinite rest on each side.
+    fn frame(&self, margin: usize) -> Frame {
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        let margin_corner = Point2::new(margin as i64, margin as i64);
+        let canvas = Grid::from_fn(width + 2 * margin, height + 2 * margin, |x, y| {
+            self.is_light(Point2::from((x, y)) - margin_corner)
+        });
+        Frame::grey(&canvas, |_, &light| if light { 255 } else { 0 })
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.pixels.rows() {
+            for &p in row {
+                write!(f, "{}", if p { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "")
+    }
+}
+
+fn check_pixels(line: &str) -> Result<(), AocError> {
+    match line.find(|c| c != '#' && c != '.') {
+        Some(col) => Err(AocError::new("expected '#' or '.'").at_column(col)),
+        None => Ok(()),
+    }
+}
+
+pub struct TrenchMap {
+    /// Whether the pixel with neighbourhood `i` is light.
+    algorithm: [bool; 512],
+    image: Image,
+}
+
+impl TrenchMap {
+    pub fn light_pixels_after(&self, steps: usize) -> usize {
+        let mut img = self.image.enhance(&self.algorithm);
+        for _ in 1..steps {
+            img = img.enhance(&self.algorithm);
+        }
+        img.count_light()
+    }
+}
+
+/// Writes the image before and after each of the enhancements of part 2,
+/// all at the size of the last one. Returns the number of frames.
+pub fn frames(lines: &[String], dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let map = Day20::parse(lines)?;
+    let mut frames = Frames::new(dir, "day20")?;
+    let mut img = map.image.clone();
+    frames.push(&img.frame(STEPS))?;
+    for step in 1..=STEPS {
+        img = img.enhance(&map.algorithm);
+        frames.push(&img.frame(STEPS - step))?;
+    }
+    Ok(frames.count())
+}
+
+/// Enhances the image once per step for `aoc repl`, as often as part 2 does.
+struct Enhancing {
+    map: TrenchMap,
+    steps: usize,
+}
+
+impl Simulation for Enhancing {
+    fn step(&mut self) -> Option<String> {
+        if self.steps == STEPS {
+            return None;
+        }
+        self.map.image = self.map.image.enhance(&self.map.algorithm);
+        self.steps += 1;
+        let rest = if self.map.image.other_pixels {
+            "light"
+        } else {
+            "dark"
+        };
+        Some(format!(
+            "{} light pixels, the rest is {}",
+            self.map.image.count_light(),
+            rest
+        ))
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["light", ..] => Ok(self.map.image.count_light() as u64 >= condition_count(condition)?),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[("light N", "at least N light pixels")]
+    }
+}
+
+impl fmt::Display for Enhancing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.map.image)
+    }
+}
+
+/// The image of `lines`, enhancement by enhancement.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    Ok(Box::new(Enhancing {
+        map: Day20::parse(lines)?,
+        steps: 0,
+    }))
+}
+
+/// Light pixels after enhancing the image 2 and 50 times.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = TrenchMap;
+
+    fn parse(lines: &[String]) -> Result<TrenchMap, AocError> {
+        let algorithm = first_line(lines)?;
+        check_pixels(algorithm).map_err(|e| e.at_line(0))?;
+        if algorithm.len() != 512 {
+            return Err(AocError::new("algorithm must have 512 characters").at_line(0));
+        }
+        if lines.len() < 3 || !lines[1].is_empty() {
+            return Err(AocError::new("expected an empty line followed by the image").at_line(1));
+        }
+        let pixels = Grid::parse(&lines[2..], |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .map_err(|e| e.after_lines(2))?;
+        let mut bits = algorithm.chars().map(|c| c == '#');
+        Ok(TrenchMap {
+            algorithm: std::array::from_fn(|_| bits.next().unwrap()),
+            image: Image::new(pixels),
+        })
+    }
+
+    fn part1(map: &TrenchMap) -> Result<Answer, AocError> {
+        Ok(map.light_pixels_after(2).into())
+    }
+
+    fn part2(map: &TrenchMap) -> Result<Answer, AocError> {
+        Ok(map.light_pixels_after(STEPS).into())
+    }
+}
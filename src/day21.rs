@@ -0,0 +1,119 @@
+//! Day 21: Dirac Dice, a practice game and the quantum one.
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Solution};
+use cached::proc_macro::cached;
+use regex::Regex;
+use std::cmp::min;
+
+/// Part 1 plays with the deterministic die, part 2 counts wins with the Dirac die.
+pub struct Day21;
+
+impl Solution for Day21 {
+    /// Zero-based starting positions of both players.
+    type Input = (u32, u32);
+
+    fn parse(lines: &[String]) -> Result<(u32, u32), AocError> {
+        let re = Regex::new(r"^Player . starting position: (\d+)$").unwrap();
+        let position = |i: usize| -> Result<u32, AocError> {
+            let line = lines
+                .get(i)
+                .ok_or_else(|| AocError::new("expected two players"))?;
+            let caps = re.captures(line).ok_or_else(|| {
+                AocError::new("expected `Player <n> starting position: <pos>`").at_line(i)
+            })?;
+            let pos = caps.get(1).unwrap().as_str();
+            match parse_at(line, pos).map_err(|e| e.at_line(i))? {
+                p @ 1..=10 => Ok(p - 1),
+                _ => Err(AocError::new("position must be between 1 and 10")
+                    .at(line, pos)
+                    .at_line(i)),
+            }
+        };
+        Ok((position(0)?, position(1)?))
+    }
+
+    fn part1(&(p1, p2): &(u32, u32)) -> Result<Answer, AocError> {
+        Ok(practice_game(p1, p2).into())
+    }
+
+    fn part2(&(p1, p2): &(u32, u32)) -> Result<Answer, AocError> {
+        let (s1, s2) = play(p1, 0, p2, 0);
+        Ok(Answer::Ints(vec![s1 as i64, s2 as i64]))
+    }
+}
+
+fn practice_game(mut p1: u32, mut p2: u32) -> u32 {
+    let mut score1: u32 = 0;
+    let mut score2: u32 = 0;
+    let mut is_first = true;
+    let mut rolls: u32 = 0;
+    let mut dice: u32 = 0;
+    let mut nvd = || {
+        rolls += 1;
+        dice = dice % 100 + 1;
+        dice
+    };
+    while score1 < 1000 && score2 < 1000 {
+        let moves = nvd() + nvd() + nvd();
+        if is_first {
+            p1 = (p1 + moves) % 10;
+            score1 += p1 + 1;
+        } else {
+            p2 = (p2 + moves) % 10;
+            score2 += p2 + 1;
+        }
+        is_first = !is_first;
+    }
+    min(score1, score2) * rolls
+}
+
+#[cached]
+fn play(p1: u32, s1: u32, p2: u32, s2: u32) -> (u64, u64) {
+    let mut w1 = 0;
+    let mut w2 = 0;
+    for (d1, d2, d3) in possible_universe() {
+        let p1c = (p1 + d1 + d2 + d3) % 10;
+        let s1c = s1 + p1c + 1;
+        if s1c >= 21 {
+            w1 += 1
+        } else {
+            let (w2c, w1c) = play(p2, s2, p1c, s1c);
+            w1 += w1c;
+            w2 += w2c;
+        }
+    }
+    (w1, w2)
+}
+
+fn possible_universe() -> Vec<(u32, u32, u32)> {
+    vec![
+        (1, 1, 1),
+        (1, 1, 2),
+        (1, 1, 3),
+        (1, 2, 1),
+        (1, 2, 2),
+        (1, 2, 3),
+        (1, 3, 1),
+        (1, 3, 2),
+        (1, 3, 3),
+        (2, 1, 1),
+        (2, 1, 2),
+        (2, 1, 3),
+        (2, 2, 1),
+        (2, 2, 2),
+        (2, 2, 3),
+        (2, 3, 1),
+        (2, 3, 2),
+        (2, 3, 3),
+        (3, 1, 1),
+        (3, 1, 2),
+        (3, 1, 3),
+        (3, 2, 1),
+        (3, 2, 2),
+        (3, 2, 3),
+        (3, 3, 1),
+        (3, 3, 2),
+        (3, 3, 3),
+    ]
+}
@@ -0,0 +1,118 @@
+//! Day 22: Reactor Reboot, switching cuboids on and off.
+
+use crate::error::{parse_at, parse_lines, AocError};
+use crate::point::{Bounds3, Point3};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// A line of the input, turning a cuboid on or off.
+#[derive(Debug, Clone)]
+pub struct RebootStep {
+    is_on: bool,
+    cuboid: Bounds3,
+}
+
+impl RebootStep {
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+    pub fn cuboid(&self) -> &Bounds3 {
+        &self.cuboid
+    }
+}
+
+impl FromStr for RebootStep {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^(on|off) x=(.*)\.\.(.*),y=(.*)\.\.(.*),z=(.*)\.\.(.*)$").unwrap();
+        let cap = re
+            .captures(s)
+            .ok_or_else(|| AocError::new("expected `<on|off> x=A..B,y=C..D,z=E..F`"))?;
+        let field = |i| parse_at(s, cap.get(i).unwrap().as_str());
+        let cuboid = Bounds3 {
+            min: Point3::new(field(2)?, field(4)?, field(6)?),
+            max: Point3::new(field(3)?, field(5)?, field(7)?),
+        };
+        let d = cuboid.max - cuboid.min;
+        if d.x < 0 || d.y < 0 || d.z < 0 {
+            return Err(AocError::new("cuboid bounds must be in ascending order"));
+        }
+        Ok(RebootStep {
+            is_on: &cap[1] == "on",
+            cuboid,
+        })
+    }
+}
+
+/// Cubes on within the initialization area, and in the whole reactor.
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<RebootStep>;
+
+    fn parse(lines: &[String]) -> Result<Vec<RebootStep>, AocError> {
+        parse_lines(lines)
+    }
+
+    fn part1(rebot_steps: &Vec<RebootStep>) -> Result<Answer, AocError> {
+        Ok(task1(rebot_steps).into())
+    }
+
+    fn part2(rebot_steps: &Vec<RebootStep>) -> Result<Answer, AocError> {
+        Ok(task2(rebot_steps).into())
+    }
+}
+
+/// Cubes left on, switching them one by one. Stops at the first step
+/// outside the initialization region.
+pub fn task1(rebot_steps: &[RebootStep]) -> usize {
+    let mut reactor = HashSet::with_capacity(1000000);
+    for step in rebot_steps {
+        let c = &step.cuboid;
+        if c.min.x < -50 || c.min.x > 50 {
+            break;
+        }
+        for p in c.points() {
+            if step.is_on {
+                reactor.insert(p);
+            } else {
+                reactor.remove(&p);
+            }
+        }
+    }
+    reactor.len()
+}
+
+/// Cubes left on, adding the volumes of the cuboids and subtracting the
+/// ones of their intersections.
+pub fn task2(rebot_steps: &[RebootStep]) -> u64 {
+    let mut reactor: Vec<RebootStep> = Vec::new();
+    for step in rebot_steps {
+        let mut to_add = Vec::with_capacity(reactor.len() + 1);
+        for s in reactor.iter() {
+            if let Some(c) = s.cuboid.intersection(&step.cuboid) {
+                to_add.push(RebootStep {
+                    is_on: !s.is_on,
+                    cuboid: c,
+                })
+            }
+        }
+        if step.is_on {
+            to_add.push(step.clone())
+        }
+        reactor.extend(to_add);
+    }
+    // The partial sums can go below zero, only the total can't.
+    let mut c: i128 = 0;
+    for s in reactor {
+        if s.is_on {
+            c += s.cuboid.volume() as i128;
+        } else {
+            c -= s.cuboid.volume() as i128;
+        }
+    }
+    c as u64
+}
@@ -0,0 +1,335 @@
+//! Day 23: Amphipod, sorting amphipods into their rooms.
+
+use crate::error::AocError;
+use crate::repl::{condition_count, Simulation};
+use crate::search::{self, SearchProblem, SearchResult};
+use crate::solution::{Answer, Solution};
+use lazy_static::lazy_static;
+use num::signum;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+lazy_static! {
+    static ref AMPHIPOD_TYPES: HashMap<char, u32> =
+        HashMap::from_iter(vec![('A', 1), ('B', 10), ('C', 100), ('D', 1000)]);
+    static ref EXITS: HashMap<usize, char> =
+        HashMap::from_iter(vec![(2, 'A'), (4, 'B'), (6, 'C'), (8, 'D')]);
+    static ref ENTRANCE: HashMap<char, usize> =
+        HashMap::from_iter(vec![('A', 2), ('B', 4), ('C', 6), ('D', 8)]);
+}
+
+/// The hallway and the four side rooms, listed from the hallway down.
+/// `.` marks free places.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Burrow {
+    hall: [char; 11],
+    rooms: HashMap<char, Vec<char>>,
+}
+
+impl Hash for Burrow {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hall.hash(state);
+        for room_type in ['A', 'B', 'C', 'D'] {
+            self.rooms[&room_type].hash(state);
+        }
+    }
+}
+
+impl Burrow {
+    pub fn new(
+        room_a: Vec<char>,
+        room_b: Vec<char>,
+        room_c: Vec<char>,
+        room_d: Vec<char>,
+    ) -> Burrow {
+        let mut rooms = HashMap::new();
+        rooms.insert('A', room_a);
+        rooms.insert('B', room_b);
+        rooms.insert('C', room_c);
+        rooms.insert('D', room_d);
+        Burrow {
+            rooms,
+            hall: ['.'; 11],
+        }
+    }
+
+    fn can_pass(&self, from: usize, to: usize) -> bool {
+        let d = signum(from as i32 - to as i32);
+        let mut c = to;
+        while c != from {
+            if self.hall[c] != '.' {
+                return false;
+            }
+            c = (c as i32 + d) as usize;
+        }
+        true
+    }
+
+    fn can_enter(&self, amphipod_type: char) -> bool {
+        self.rooms[&amphipod_type]
+            .iter()
+            .all(|&c| c == '.' || c == amphipod_type)
+    }
+
+    /// Moves the amphipod at `position` of the hall into its room.
+    /// Returns the new burrow with the energy spent.
+    fn enter(&self, position: usize) -> (Burrow, u32) {
+        let mut res = self.clone();
+        let amphipod_type = res.hall[position];
+        res.hall[position] = '.';
+        let entrance = ENTRANCE[&amphipod_type];
+        let mut cost = (position as i32 - entrance as i32).unsigned_abs() + 1;
+        let room = res.rooms.get_mut(&amphipod_type).unwrap();
+        for (i, c) in room.iter_mut().enumerate().rev() {
+            if *c == '.' {
+                *c = amphipod_type;
+                cost += i as u32;
+                break;
+            }
+        }
+        (res, cost * AMPHIPOD_TYPES[&amphipod_type])
+    }
+
+    fn can_exit(&self, amphipod_type: &char) -> bool {
+        self.rooms[amphipod_type]
+            .iter()
+            .all(|c| *c == '.' || c == amphipod_type)
+    }
+
+    /// Moves the topmost amphipod of a room to `position` of the hall.
+    fn go_out(&self, room_type: &char, position: usize) -> (Burrow, u32) {
+        let mut res = self.clone();
+        let room = res.rooms.get_mut(room_type).unwrap();
+        let exit = ENTRANCE[room_type];
+        let mut cost = (position as i32 - exit as i32).unsigned_abs() + 1;
+        let mut amphipod_type = ' ';
+        for (i, c) in room.iter_mut().enumerate() {
+            if *c != '.' {
+                amphipod_type = *c;
+                res.hall[position] = amphipod_type;
+                *c = '.';
+                cost += i as u32;
+                break;
+            }
+        }
+        (res, cost * AMPHIPOD_TYPES[&amphipod_type])
+    }
+
+    /// Every burrow one move away, with the energy the move takes.
+    pub fn possible_moves(&self) -> Vec<(Burrow, u32)> {
+        let mut res = Vec::new();
+
+        // enter
+        for i in 0..self.hall.len() {
+            if self.hall[i] != '.' {
+                let amphipod_type = self.hall[i];
+                let entrance = ENTRANCE[&amphipod_type];
+                if !self.can_pass(i, entrance) {
+                    continue;
+                }
+                if !self.can_enter(amphipod_type) {
+                    continue;
+                }
+                res.push(self.enter(i));
+            }
+        }
+        // go out
+        for (room_type, _) in AMPHIPOD_TYPES.iter() {
+            if self.can_exit(room_type) {
+                continue;
+            }
+            let exit = ENTRANCE[room_type];
+            for i in 0..self.hall.len() {
+                if EXITS.contains_key(&i) {
+                    continue;
+                }
+                if !self.can_pass(exit, i) {
+                    continue;
+                }
+                res.push(self.go_out(room_type, i));
+            }
+        }
+
+        res
+    }
+
+    /// Whether every amphipod is in its own room.
+    pub fn is_win(&self) -> bool {
+        for (room_type, _) in AMPHIPOD_TYPES.iter() {
+            if self.rooms[room_type].iter().any(|c| c != room_type) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "#############")?;
+        write!(f, "#")?;
+        for c in self.hall {
+            write!(f, "{}", c)?;
+        }
+        writeln!(f, "#")?;
+        writeln!(
+            f,
+            "###{}#{}#{}#{}###",
+            self.rooms[&'A'][0], self.rooms[&'B'][0], self.rooms[&'C'][0], self.rooms[&'D'][0]
+        )?;
+        for i in 1..self.rooms[&'A'].len() {
+            writeln!(
+                f,
+                "  #{}#{}#{}#{}#  ",
+                self.rooms[&'A'][i], self.rooms[&'B'][i], self.rooms[&'C'][i], self.rooms[&'D'][i]
+            )?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+fn parse_state(lines: &[String]) -> Result<[Vec<char>; 4], AocError> {
+    let mut state = [vec![], vec![], vec![], vec![]];
+    let re = Regex::new(r"#([A-D])#([A-D])#([A-D])#([A-D])#").unwrap();
+    for ln in 2..4 {
+        let caps = lines
+            .get(ln)
+            .and_then(|line| re.captures(line))
+            .ok_or_else(|| AocError::new("expected a row of rooms like `#A#B#C#D#`").at_line(ln))?;
+        for (i, room) in state.iter_mut().enumerate() {
+            room.push(caps[i + 1].chars().next().unwrap());
+        }
+    }
+    for amphipod_type in ['A', 'B', 'C', 'D'] {
+        if state
+            .iter()
+            .flatten()
+            .filter(|&&c| c == amphipod_type)
+            .count()
+            != 2
+        {
+            return Err(AocError::new(format!(
+                "expected two amphipods of type {}",
+                amphipod_type
+            )));
+        }
+    }
+    Ok(state)
+}
+
+/// Moving all amphipods into their own rooms.
+struct Organize {
+    start: Burrow,
+}
+
+impl SearchProblem for Organize {
+    type State = Burrow;
+
+    fn start(&self) -> Burrow {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, burrow: &Burrow) -> bool {
+        burrow.is_win()
+    }
+
+    fn successors(&self, burrow: &Burrow) -> Vec<(Burrow, u64)> {
+        burrow
+            .possible_moves()
+            .into_iter()
+            .map(|(b, cost)| (b, cost as u64))
+            .collect()
+    }
+}
+
+fn cheapest_moves(rooms: &[Vec<char>; 4]) -> SearchResult<Burrow> {
+    let b = Burrow::new(
+        rooms[0].clone(),
+        rooms[1].clone(),
+        rooms[2].clone(),
+        rooms[3].clone(),
+    );
+    search::dijkstra(&Organize { start: b }).unwrap()
+}
+
+fn least_energy(rooms: &[Vec<char>; 4]) -> u64 {
+    cheapest_moves(rooms).cost
+}
+
+/// Replays the cheapest way to organize the amphipods move by move, for `aoc repl`.
+struct Moves {
+    path: Vec<Burrow>,
+    moved: usize,
+    energy: u64,
+}
+
+impl Simulation for Moves {
+    fn step(&mut self) -> Option<String> {
+        let next = self.path.get(self.moved + 1)?;
+        let (_, cost) = self.path[self.moved]
+            .possible_moves()
+            .into_iter()
+            .filter(|(b, _)| b == next)
+            .min_by_key(|&(_, cost)| cost)
+            .unwrap();
+        self.moved += 1;
+        self.energy += cost as u64;
+        Some(format!("energy {}, {} in total", cost, self.energy))
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["energy", ..] => Ok(self.energy >= condition_count(condition)?),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[("energy N", "at least N energy spent")]
+    }
+}
+
+impl fmt::Display for Moves {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path[self.moved])
+    }
+}
+
+/// The cheapest moves for the short diagram of `lines`, one by one.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    Ok(Box::new(Moves {
+        path: cheapest_moves(&parse_state(lines)?).path,
+        moved: 0,
+        energy: 0,
+    }))
+}
+
+/// Least energy to organize the amphipods, with the short and the unfolded diagram.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = [Vec<char>; 4];
+
+    fn parse(lines: &[String]) -> Result<[Vec<char>; 4], AocError> {
+        parse_state(lines)
+    }
+
+    fn part1(rooms: &[Vec<char>; 4]) -> Result<Answer, AocError> {
+        Ok(least_energy(rooms).into())
+    }
+
+    fn part2(rooms: &[Vec<char>; 4]) -> Result<Answer, AocError> {
+        let mut rooms = rooms.clone();
+        rooms[0].insert(1, 'D');
+        rooms[0].insert(2, 'D');
+        rooms[1].insert(1, 'C');
+        rooms[1].insert(2, 'B');
+        rooms[2].insert(1, 'B');
+        rooms[2].insert(2, 'A');
+        rooms[3].insert(1, 'A');
+        rooms[3].insert(2, 'C');
+        Ok(least_energy(&rooms).into())
+    }
+}
@@ -0,0 +1,358 @@
+//! Day 24: Arithmetic Logic Unit, and the MONAD model number checker running on it.
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+const REGISTER_NAMES: [char; 4] = ['w', 'x', 'y', 'z'];
+const X: usize = 1;
+const Y: usize = 2;
+const Z: usize = 3;
+
+/// Values of `w`, `x`, `y` and `z`.
+pub type Registers = [i64; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Reg(usize),
+    Num(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+}
+
+fn parse_register(line: &str, part: &str) -> Result<usize, AocError> {
+    let mut chars = part.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if REGISTER_NAMES.contains(&c) => {
+            Ok(REGISTER_NAMES.iter().position(|&r| r == c).unwrap())
+        }
+        _ => Err(AocError::new(format!("unknown register {:?}", part)).at(line, part)),
+    }
+}
+
+fn parse_operand(line: &str, part: &str) -> Result<Operand, AocError> {
+    if part.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        parse_register(line, part).map(Operand::Reg)
+    } else {
+        parse_at(line, part).map(Operand::Num)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split_whitespace().collect();
+        match parts[..] {
+            ["inp", a] => Ok(Instruction::Inp(parse_register(s, a)?)),
+            [op, a, b] => {
+                let a = parse_register(s, a)?;
+                let b = parse_operand(s, b)?;
+                match op {
+                    "add" => Ok(Instruction::Add(a, b)),
+                    "mul" => Ok(Instruction::Mul(a, b)),
+                    "div" => Ok(Instruction::Div(a, b)),
+                    "mod" => Ok(Instruction::Mod(a, b)),
+                    "eql" => Ok(Instruction::Eql(a, b)),
+                    _ => Err(AocError::new(format!("unknown instruction {:?}", op)).at(s, op)),
+                }
+            }
+            _ => Err(AocError::new("expected `inp a` or `<op> a b`")),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Reg(r) => write!(f, "{}", REGISTER_NAMES[*r]),
+            Operand::Num(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (op, a, b) = match self {
+            Instruction::Inp(a) => return write!(f, "inp {}", REGISTER_NAMES[*a]),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", op, REGISTER_NAMES[*a], b)
+    }
+}
+
+pub fn parse_program(lines: &[String]) -> Result<Vec<Instruction>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: AocError| e.at_line(i)))
+        .collect()
+}
+
+/// Runs `program` reading `inp` values from `input`. `trace` is called after
+/// every instruction with its index and the registers it left behind.
+pub fn run_traced<F>(
+    program: &[Instruction],
+    input: &[i64],
+    mut trace: F,
+) -> Result<Registers, AocError>
+where
+    F: FnMut(usize, &Instruction, &Registers),
+{
+    let mut regs = [0; 4];
+    let mut input = input.iter();
+    for (pc, ins) in program.iter().enumerate() {
+        let value = |b: &Operand, regs: &Registers| match *b {
+            Operand::Reg(r) => regs[r],
+            Operand::Num(n) => n,
+        };
+        let fail = |reason: &str| AocError::new(format!("{}: {}", ins, reason)).at_line(pc);
+        match ins {
+            Instruction::Inp(a) => regs[*a] = *input.next().ok_or_else(|| fail("out of input"))?,
+            Instruction::Add(a, b) => {
+                regs[*a] = regs[*a]
+                    .checked_add(value(b, &regs))
+                    .ok_or_else(|| fail("overflow"))?
+            }
+            Instruction::Mul(a, b) => {
+                regs[*a] = regs[*a]
+                    .checked_mul(value(b, &regs))
+                    .ok_or_else(|| fail("overflow"))?
+            }
+            Instruction::Div(a, b) => {
+                let b = value(b, &regs);
+                if b == 0 {
+                    return Err(fail("division by zero"));
+                }
+                regs[*a] = regs[*a].checked_div(b).ok_or_else(|| fail("overflow"))?;
+            }
+            Instruction::Mod(a, b) => {
+                let b = value(b, &regs);
+                if b <= 0 {
+                    return Err(fail("modulo by a non-positive number"));
+                }
+                if regs[*a] < 0 {
+                    return Err(fail("modulo of a negative number"));
+                }
+                regs[*a] %= b;
+            }
+            Instruction::Eql(a, b) => regs[*a] = (regs[*a] == value(b, &regs)) as i64,
+        }
+        trace(pc, ins, &regs);
+    }
+    Ok(regs)
+}
+
+pub fn run(program: &[Instruction], input: &[i64]) -> Result<Registers, AocError> {
+    run_traced(program, input, |_, _, _| ())
+}
+
+/// Runs `program` on the digits of `model` printing every step.
+pub fn trace(lines: &[String], model: &str) -> Result<(), AocError> {
+    let program = parse_program(lines)?;
+    let input = model
+        .chars()
+        .map(|c| c.to_digit(10).map(|d| d as i64))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| AocError::new(format!("bad model number {:?}", model)))?;
+    let regs = run_traced(&program, &input, |pc, ins, regs| {
+        println!(
+            "{:>4}  {:<10} w={} x={} y={} z={}",
+            pc + 1,
+            ins.to_string(),
+            regs[0],
+            regs[1],
+            regs[2],
+            regs[3]
+        );
+    })?;
+    let verdict = if regs[Z] == 0 { "valid" } else { "invalid" };
+    println!("{} is {}", model, verdict);
+    Ok(())
+}
+
+/// MONAD checks each digit with a block of 18 instructions which differ only
+/// in the `div z`, `add x` and `add y` constants. `z` is used as a stack of
+/// base 26 numbers: a block with `div z 1` pushes `digit + offset`, one with
+/// `div z 26` pops and only avoids pushing again if `digit == popped + check`.
+pub(crate) const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z _", "add x _", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y _",
+    "mul y x", "add z y",
+];
+pub(crate) const DIGITS: usize = 14;
+
+#[derive(Debug)]
+struct Block {
+    div: i64,
+    check: i64,
+    offset: i64,
+}
+
+fn parse_blocks(program: &[Instruction]) -> Result<Vec<Block>, AocError> {
+    if program.len() != BLOCK.len() * DIGITS {
+        return Err(AocError::new(format!(
+            "expected {} instructions, found {}",
+            BLOCK.len() * DIGITS,
+            program.len()
+        )));
+    }
+    let constant = |pc: usize, expected: fn(i64) -> Instruction| match program[pc] {
+        Instruction::Div(_, Operand::Num(n)) | Instruction::Add(_, Operand::Num(n))
+            if program[pc] == expected(n) =>
+        {
+            Ok(n)
+        }
+        ins => Err(AocError::new(format!(
+            "expected `{}`, found `{}`",
+            BLOCK[pc % BLOCK.len()],
+            ins
+        ))
+        .at_line(pc)),
+    };
+    let mut blocks = Vec::with_capacity(DIGITS);
+    for start in (0..program.len()).step_by(BLOCK.len()) {
+        for (k, expected) in BLOCK.iter().enumerate() {
+            let ins = program[start + k].to_string();
+            if !expected.ends_with('_') && ins != *expected {
+                return Err(
+                    AocError::new(format!("expected `{}`, found `{}`", expected, ins))
+                        .at_line(start + k),
+                );
+            }
+        }
+        let block = Block {
+            div: constant(start + 4, |n| Instruction::Div(Z, Operand::Num(n)))?,
+            check: constant(start + 5, |n| Instruction::Add(X, Operand::Num(n)))?,
+            offset: constant(start + 15, |n| Instruction::Add(Y, Operand::Num(n)))?,
+        };
+        // Pushed digits must stay base 26 digits for `mod x 26` to pop them.
+        if block.div == 1 && !(-1..=16).contains(&block.offset) {
+            return Err(AocError::new(format!(
+                "block {} pushes a digit plus {}, which doesn't fit in base 26",
+                blocks.len() + 1,
+                block.offset
+            ))
+            .at_line(start + 15));
+        }
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+/// Digit `second` of a valid model number must equal digit `first` plus `diff`.
+#[derive(Debug)]
+struct Constraint {
+    first: usize,
+    second: usize,
+    diff: i64,
+}
+
+pub struct Monad {
+    constraints: Vec<Constraint>,
+}
+
+impl Monad {
+    fn new(program: Vec<Instruction>) -> Result<Monad, AocError> {
+        let blocks = parse_blocks(&program)?;
+        let mut stack = Vec::new();
+        let mut constraints = Vec::new();
+        for (i, block) in blocks.iter().enumerate() {
+            let line = i * BLOCK.len() + 4;
+            match block.div {
+                // A pushed digit plus offset can't be popped by the very same block.
+                1 if block.check > 9 => stack.push((i, block.offset)),
+                26 => {
+                    let (first, offset) = stack
+                        .pop()
+                        .ok_or_else(|| AocError::new("nothing to pop").at_line(line))?;
+                    let diff = offset + block.check;
+                    if diff.abs() > 8 {
+                        return Err(AocError::new(format!(
+                            "digits {} and {} can never match",
+                            first + 1,
+                            i + 1
+                        ))
+                        .at_line(line));
+                    }
+                    constraints.push(Constraint {
+                        first,
+                        second: i,
+                        diff,
+                    });
+                }
+                _ => {
+                    return Err(
+                        AocError::new("unsupported block, expected a push or a pop").at_line(line)
+                    )
+                }
+            }
+        }
+        if !stack.is_empty() {
+            return Err(AocError::new("z never gets back to 0"));
+        }
+        let monad = Monad { constraints };
+        // Checked here so that solving can't fail.
+        for largest in [true, false] {
+            let model = monad.model_number(largest);
+            if run(&program, &monad.digits(largest))?[Z] != 0 {
+                return Err(AocError::new(format!("MONAD rejects {}", model)));
+            }
+        }
+        Ok(monad)
+    }
+
+    /// Digits of the largest or smallest model number the constraints allow.
+    fn digits(&self, largest: bool) -> [i64; DIGITS] {
+        let mut digits = [0; DIGITS];
+        for c in &self.constraints {
+            // Push the digit with the least room as far as it goes.
+            let (first, second) = match (largest, c.diff >= 0) {
+                (true, true) => (9 - c.diff, 9),
+                (true, false) => (9, 9 + c.diff),
+                (false, true) => (1, 1 + c.diff),
+                (false, false) => (1 - c.diff, 1),
+            };
+            digits[c.first] = first;
+            digits[c.second] = second;
+        }
+        digits
+    }
+
+    /// Largest or smallest model number accepted by the program.
+    fn model_number(&self, largest: bool) -> i64 {
+        self.digits(largest).iter().fold(0, |n, d| n * 10 + d)
+    }
+}
+
+/// Largest and smallest model numbers MONAD accepts.
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+
+    fn parse(lines: &[String]) -> Result<Monad, AocError> {
+        Monad::new(parse_program(lines)?)
+    }
+
+    fn part1(monad: &Monad) -> Result<Answer, AocError> {
+        Ok(monad.model_number(true).into())
+    }
+
+    fn part2(monad: &Monad) -> Result<Answer, AocError> {
+        Ok(monad.model_number(false).into())
+    }
+}
@@ -0,0 +1,144 @@
+//! Day 25: Sea Cucumber, herds moving east and south on a wrapping map.
+
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use std::fmt;
+use std::mem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+/// The map and a second buffer of the same size the next state is written
+/// to, so a step never allocates.
+#[derive(Debug, Clone)]
+pub struct Herds {
+    map: Grid<Cell>,
+    next: Grid<Cell>,
+}
+
+impl Herds {
+    fn new(lines: &[String]) -> Result<Herds, AocError> {
+        let map = Grid::parse(lines, |c| match c {
+            '.' => Some(Cell::Empty),
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            _ => None,
+        })?;
+        let next = map.clone();
+        Ok(Herds { map, next })
+    }
+    /// Moves every cucumber of `herd` that has a free cell in front of it.
+    /// Returns how many moved.
+    fn move_herd(&mut self, herd: Cell, (dx, dy): (usize, usize)) -> usize {
+        let (width, height) = (self.map.width(), self.map.height());
+        for cell in self.next.values_mut() {
+            *cell = Cell::Empty;
+        }
+        let mut moved = 0;
+        for ((x, y), &cell) in self.map.iter() {
+            if cell == Cell::Empty {
+                continue;
+            }
+            let target = ((x + dx) % width, (y + dy) % height);
+            if cell == herd && self.map[target] == Cell::Empty {
+                self.next[target] = cell;
+                moved += 1;
+            } else {
+                self.next[(x, y)] = cell;
+            }
+        }
+        mem::swap(&mut self.map, &mut self.next);
+        moved
+    }
+    /// Returns whether any sea cucumber moved.
+    pub fn step(&mut self) -> bool {
+        let east = self.move_herd(Cell::East, (1, 0));
+        let south = self.move_herd(Cell::South, (0, 1));
+        east + south > 0
+    }
+}
+
+impl fmt::Display for Herds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.map.rows() {
+            for cell in row {
+                match cell {
+                    Cell::Empty => write!(f, ".")?,
+                    Cell::East => write!(f, ">")?,
+                    Cell::South => write!(f, "v")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        write!(f, "")
+    }
+}
+
+/// `None` if the herds never stop. Steps are deterministic and maps finite,
+/// so herds that never stop come back to a map they had before. Brent's
+/// cycle detection notices with a single saved map, comparing it with each
+/// new one and saving the current map again after 1, 2, 4... steps.
+fn first_step_without_moves(herds: &Herds) -> Option<usize> {
+    let mut herds = herds.clone();
+    let mut saved = herds.map.clone();
+    let mut next_save = 1;
+    let mut res = 1;
+    while herds.step() {
+        if herds.map == saved {
+            return None;
+        }
+        if res == next_save {
+            saved.clone_from(&herds.map);
+            next_save *= 2;
+        }
+        res += 1;
+    }
+    Some(res)
+}
+
+/// Prints the map after each of the given numbers of steps.
+pub fn render(lines: &[String], steps: &[usize]) -> Result<(), AocError> {
+    let mut herds = Herds::new(lines)?;
+    let last = steps.iter().copied().max().unwrap_or(0);
+    for step in 0..=last {
+        if step > 0 {
+            herds.step();
+        }
+        if steps.contains(&step) {
+            match step {
+                0 => println!("Initial state:"),
+                1 => println!("After 1 step:"),
+                _ => println!("After {} steps:", step),
+            }
+            println!("{}", herds);
+        }
+    }
+    Ok(())
+}
+
+/// Part 1 is the first step on which no sea cucumber moves.
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Herds;
+
+    fn parse(lines: &[String]) -> Result<Herds, AocError> {
+        Herds::new(lines)
+    }
+
+    fn part1(herds: &Herds) -> Result<Answer, AocError> {
+        first_step_without_moves(herds)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::new("the herds never stop"))
+    }
+
+    fn part2(_herds: &Herds) -> Result<Answer, AocError> {
+        // Day 25 only has one puzzle, the second star is for all the others.
+        Ok(Answer::Str("no second part".to_string()))
+    }
+}
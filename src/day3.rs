@@ -0,0 +1,126 @@
+//! Day 3: Binary Diagnostic, power consumption and life support ratings.
+
+use crate::bits::BitWriter;
+use crate::error::{first_line, AocError};
+use crate::solution::{Answer, Solution};
+use num_bigint::BigUint;
+
+/// The diagnostic report, its lines packed into bits both by rows and by
+/// columns. Lines may be any number of bits wide.
+#[derive(Debug, Clone)]
+pub struct Report {
+    width: usize,
+    /// The lines sorted, which orders them like the numbers they spell.
+    rows: Vec<BitWriter>,
+    /// Bit `j` of column `i` is bit `i` of line `j`.
+    columns: Vec<BitWriter>,
+}
+
+impl Report {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+/// Part 1 is the power consumption, part 2 the life support rating.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+
+    fn parse(lines: &[String]) -> Result<Report, AocError> {
+        let width = first_line(lines)?.len();
+        if width == 0 {
+            return Err(AocError::new("report lines need at least one bit").at_line(0));
+        }
+        let mut rows = Vec::with_capacity(lines.len());
+        let mut columns = vec![BitWriter::new(); width];
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                return Err(AocError::new(format!("expected {} bits", width)).at_line(i));
+            }
+            let mut row = BitWriter::new();
+            for (col, c) in line.chars().enumerate() {
+                let bit = match c {
+                    '0' => false,
+                    '1' => true,
+                    _ => return Err(AocError::new("expected 0 or 1").at_column(col).at_line(i)),
+                };
+                row.write_bit(bit);
+                columns[col].write_bit(bit);
+            }
+            rows.push(row);
+        }
+        rows.sort();
+        Ok(Report {
+            width,
+            rows,
+            columns,
+        })
+    }
+
+    fn part1(report: &Report) -> Result<Answer, AocError> {
+        Ok(task1(report))
+    }
+
+    fn part2(report: &Report) -> Result<Answer, AocError> {
+        Ok(task2(report))
+    }
+}
+
+/// The number `bits` spell, most significant bit first.
+fn number(bits: &BitWriter) -> BigUint {
+    let bytes = bits.as_bytes();
+    BigUint::from_bytes_be(bytes) >> (bytes.len() * 8 - bits.len())
+}
+
+fn task1(report: &Report) -> Answer {
+    let mut gamma_rate = BitWriter::new();
+    let mut epsilon_rate = BitWriter::new();
+    for column in &report.columns {
+        let ones = column.count_ones();
+        // Ties, which the puzzle leaves open, count as zeros being more common.
+        let most_common = ones > report.len() - ones;
+        gamma_rate.write_bit(most_common);
+        epsilon_rate.write_bit(!most_common);
+    }
+    (number(&gamma_rate) * number(&epsilon_rate)).into()
+}
+
+/// Narrows the lines down bit by bit, keeping those whose bit is
+/// `keep(ones, zeros)`, until one is left. Sorted lines sharing their first
+/// bits are next to each other with zeros before ones at the following bit,
+/// so a binary search finds where the ones start.
+fn rating(report: &Report, keep: fn(usize, usize) -> bool) -> &BitWriter {
+    let (mut lo, mut hi) = (0, report.len());
+    for i in 0..report.width {
+        if hi - lo == 1 {
+            break;
+        }
+        let split = lo + report.rows[lo..hi].partition_point(|row| row.get(i) == Some(false));
+        let (zeros, ones) = (split - lo, hi - split);
+        if zeros == 0 || ones == 0 {
+            // All lines share this bit, so it can't tell them apart.
+            continue;
+        }
+        if keep(ones, zeros) {
+            lo = split;
+        } else {
+            hi = split;
+        }
+    }
+    // Past the last bit only copies of the same line are left.
+    &report.rows[lo]
+}
+
+fn task2(report: &Report) -> Answer {
+    let oxygen = rating(report, |ones, zeros| ones >= zeros);
+    let co2 = rating(report, |ones, zeros| ones < zeros);
+    (number(oxygen) * number(co2)).into()
+}
@@ -0,0 +1,285 @@
+//! Day 4: Giant Squid, playing bingo against the squid.
+
+use crate::error::{first_line, parse_at, parse_list, AocError};
+use crate::grid::Grid;
+use crate::repl::Simulation;
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+use itertools::Itertools;
+
+/// A board of any width and height, counting the marks of every row, column
+/// and diagonal so that a mark tells at once whether it wins.
+#[derive(Debug, Clone)]
+struct Board {
+    numbers: Grid<u32>,
+    marks: Grid<bool>,
+    /// Cells of every number on the board.
+    cells: HashMap<u32, Vec<(usize, usize)>>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    /// The diagonal from the top left corner, then the other one.
+    diagonal_marks: [usize; 2],
+    unmarked_sum: u64,
+}
+
+impl Board {
+    /// Reads a board from its rows, each paired with its line index.
+    fn new<'a, T>(sboard: T) -> Result<Board, AocError>
+    where
+        T: Iterator<Item = (usize, &'a String)>,
+    {
+        let mut rows: Vec<Vec<u32>> = Vec::new();
+        for (ln, line) in sboard {
+            let row = line
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .map(|n| parse_at(line, n).map_err(|e| e.at_line(ln)))
+                .collect::<Result<Vec<u32>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(AocError::new(format!(
+                        "board row must have {} numbers like the first one",
+                        first.len()
+                    ))
+                    .at_line(ln));
+                }
+            }
+            rows.push(row);
+        }
+        let (width, height) = (rows.first().map_or(0, Vec::len), rows.len());
+        if width == 0 {
+            return Err(AocError::new("empty board"));
+        }
+        let numbers = Grid::from_fn(width, height, |x, y| rows[y][x]);
+        let mut cells: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        for (pos, &n) in numbers.iter() {
+            cells.entry(n).or_default().push(pos);
+        }
+        Ok(Board {
+            unmarked_sum: numbers.values().map(|&n| n as u64).sum(),
+            marks: Grid::new(width, height, false),
+            numbers,
+            cells,
+            row_marks: vec![0; height],
+            column_marks: vec![0; width],
+            diagonal_marks: [0; 2],
+        })
+    }
+    /// Only square boards have diagonals.
+    fn has_diagonals(&self) -> bool {
+        self.numbers.width() == self.numbers.height()
+    }
+    /// Marks every cell holding `number`, returning whether that completes a
+    /// row, a column, or with `diagonals` a diagonal.
+    fn mark(&mut self, number: u32, diagonals: bool) -> bool {
+        let (width, height) = (self.numbers.width(), self.numbers.height());
+        let mut wins = false;
+        for &(x, y) in self.cells.get(&number).into_iter().flatten() {
+            if self.marks[(x, y)] {
+                continue;
+            }
+            self.marks[(x, y)] = true;
+            self.unmarked_sum -= number as u64;
+            self.row_marks[y] += 1;
+            self.column_marks[x] += 1;
+            wins |= self.row_marks[y] == width || self.column_marks[x] == height;
+            if self.has_diagonals() {
+                for (d, on_diagonal) in [x == y, x + y == width - 1].into_iter().enumerate() {
+                    if on_diagonal {
+                        self.diagonal_marks[d] += 1;
+                        wins |= diagonals && self.diagonal_marks[d] == width;
+                    }
+                }
+            }
+        }
+        wins
+    }
+    fn sum_unmarked(&self) -> u64 {
+        self.unmarked_sum
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.numbers.height() {
+            for x in 0..self.numbers.width() {
+                write!(
+                    f,
+                    "{:4}{}",
+                    self.numbers[(x, y)],
+                    if self.marks[(x, y)] { '*' } else { ' ' }
+                )?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "")
+    }
+}
+
+#[derive(Debug)]
+pub struct Bingo {
+    numbers: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    pub fn board_count(&self) -> usize {
+        self.boards.len()
+    }
+}
+
+/// A board winning, see [`ranking`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Win {
+    /// The board, counted from 0 in the order of the input.
+    pub board: usize,
+    /// How many numbers had been drawn, the winning one included.
+    pub draws: usize,
+    pub number: u32,
+    /// The winning number times the sum of the unmarked ones.
+    pub score: u64,
+}
+
+/// The boards in the order they win, those winning on the same draw in the
+/// order of the input. Boards that never win are left out. With `diagonals`
+/// marking a whole diagonal of a square board wins too.
+pub fn ranking(bingo: &Bingo, diagonals: bool) -> Vec<Win> {
+    let mut boards = bingo.boards.clone();
+    let mut won = vec![false; boards.len()];
+    let mut res = Vec::new();
+    for (i, &number) in bingo.numbers.iter().enumerate() {
+        for (b, board) in boards.iter_mut().enumerate() {
+            if board.mark(number, diagonals) && !won[b] {
+                won[b] = true;
+                res.push(Win {
+                    board: b,
+                    draws: i + 1,
+                    number,
+                    score: board.sum_unmarked() * number as u64,
+                });
+            }
+        }
+        if res.len() == boards.len() {
+            break;
+        }
+    }
+    res
+}
+
+/// Draws the numbers one at a time, for `aoc repl`.
+struct Draws {
+    bingo: Bingo,
+    drawn: usize,
+    /// Score of every board that won, when it won.
+    scores: Vec<Option<u64>>,
+}
+
+impl Simulation for Draws {
+    fn step(&mut self) -> Option<String> {
+        let number = *self.bingo.numbers.get(self.drawn)?;
+        self.drawn += 1;
+        let mut note = format!("drew {}", number);
+        for (i, board) in self.bingo.boards.iter_mut().enumerate() {
+            if board.mark(number, false) && self.scores[i].is_none() {
+                let score = board.sum_unmarked() * number as u64;
+                self.scores[i] = Some(score);
+                note += &format!(", board {} wins with score {}", i + 1, score);
+            }
+        }
+        Some(note)
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["win"] => Ok(self.scores.iter().any(Option::is_some)),
+            ["all-win"] => Ok(self.scores.iter().all(Option::is_some)),
+            _ => Err(format!("unknown condition: {}", condition.join(" "))),
+        }
+    }
+
+    fn conditions(&self) -> &'static [(&'static str, &'static str)] {
+        &[("win", "a board won"), ("all-win", "every board won")]
+    }
+}
+
+impl fmt::Display for Draws {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "drawn: {}",
+            self.bingo.numbers[..self.drawn].iter().join(",")
+        )?;
+        for (i, board) in self.bingo.boards.iter().enumerate() {
+            match self.scores[i] {
+                Some(score) => writeln!(f, "\nboard {}, won with score {}:", i + 1, score)?,
+                None => writeln!(f, "\nboard {}:", i + 1)?,
+            }
+            write!(f, "{}", board)?;
+        }
+        Ok(())
+    }
+}
+
+/// Bingo with the numbers of `lines` drawn one by one.
+pub fn simulation(lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    let bingo = Day4::parse(lines)?;
+    let scores = vec![None; bingo.boards.len()];
+    Ok(Box::new(Draws {
+        bingo,
+        drawn: 0,
+        scores,
+    }))
+}
+
+/// Part 1 scores the first winning board, part 2 the last one.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Bingo;
+
+    fn parse(lines: &[String]) -> Result<Bingo, AocError> {
+        let numbers = parse_list(first_line(lines)?).map_err(|e| e.at_line(0))?;
+        let mut boards = Vec::new();
+        for (key, s) in &lines
+            .iter()
+            .enumerate()
+            .skip(1)
+            .group_by(|(_, s)| !s.is_empty())
+        {
+            if key {
+                let mut s = s.peekable();
+                let first = s.peek().map(|&(ln, _)| ln).unwrap_or_default();
+                boards.push(Board::new(s).map_err(|e| e.at_line(first))?);
+            }
+        }
+        if boards.is_empty() {
+            return Err(AocError::new("no boards after the numbers"));
+        }
+        Ok(Bingo { numbers, boards })
+    }
+
+    fn part1(bingo: &Bingo) -> Result<Answer, AocError> {
+        match ranking(bingo, false).first() {
+            Some(win) => Ok(win.score.into()),
+            None => Err(AocError::new("no board wins")),
+        }
+    }
+
+    fn part2(bingo: &Bingo) -> Result<Answer, AocError> {
+        let ranking = ranking(bingo, false);
+        let losers: Vec<_> = (0..bingo.boards.len())
+            .filter(|&b| ranking.iter().all(|win| win.board != b))
+            .map(|b| b + 1)
+            .collect();
+        match losers[..] {
+            [] => Ok(ranking.last().map_or(0, |win| win.score).into()),
+            [b] => Err(AocError::new(format!("board {} never wins", b))),
+            _ => Err(AocError::new(format!(
+                "boards {} never win",
+                losers.iter().join(", ")
+            ))),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Day 5: Hydrothermal Venture, overlapping lines of vents.
+
+use crate::error::{parse_lines, AocError};
+use crate::point::{parse_point2, Point2};
+use crate::solution::{Answer, Solution};
+use num_bigint::BigUint;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// The four ways a line of vents can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    Horizontal,
+    Vertical,
+    /// `y - x` stays the same along the line.
+    Rising,
+    /// `y + x` stays the same along the line.
+    Falling,
+}
+
+impl Family {
+    const ALL: [Family; 4] = [
+        Family::Horizontal,
+        Family::Vertical,
+        Family::Rising,
+        Family::Falling,
+    ];
+
+    /// `(a, b)` such that the lines of this family are `a * x + b * y = key`.
+    /// Keys are `i128`, sums of two coordinates need the extra bit.
+    fn coefficients(self) -> (i128, i128) {
+        match self {
+            Family::Horizontal => (0, 1),
+            Family::Vertical => (1, 0),
+            Family::Rising => (-1, 1),
+            Family::Falling => (1, 1),
+        }
+    }
+
+    /// Which line of this family `p` is on.
+    fn key(self, p: Point2) -> i128 {
+        let (a, b) = self.coefficients();
+        a * p.x as i128 + b * p.y as i128
+    }
+
+    /// Where `p` is along its line: `y` on vertical lines, `x` on the others.
+    fn position(self, p: Point2) -> i64 {
+        match self {
+            Family::Vertical => p.y,
+            _ => p.x,
+        }
+    }
+
+    /// The point at `position` along the line `key`, which must be on the
+    /// plane.
+    fn point(self, key: i128, position: i64) -> Point2 {
+        let other = match self {
+            Family::Horizontal | Family::Vertical => key,
+            Family::Rising => key + position as i128,
+            Family::Falling => key - position as i128,
+        } as i64;
+        match self {
+            Family::Vertical => Point2::new(other, position),
+            _ => Point2::new(position, other),
+        }
+    }
+
+    /// Where line `key` of this family and line `other_key` of `other` meet,
+    /// if that is a point of the grid.
+    fn meet(self, key: i128, other: Family, other_key: i128) -> Option<Point2> {
+        let (a1, b1) = self.coefficients();
+        let (a2, b2) = other.coefficients();
+        // The determinant is 1 or 2 up to its sign, shifting is much
+        // cheaper than dividing `i128`s.
+        let det = a1 * b2 - a2 * b1;
+        let x = (key * b2 - other_key * b1) * det.signum();
+        let y = (a1 * other_key - a2 * key) * det.signum();
+        let shift = det.abs() / 2;
+        // Rising and falling lines only cross on the grid when their keys
+        // have the same parity.
+        if (x | y) & shift != 0 {
+            return None;
+        }
+        Some(Point2::new(
+            i64::try_from(x >> shift).ok()?,
+            i64::try_from(y >> shift).ok()?,
+        ))
+    }
+}
+
+/// A stretch of the line `key` of some family, from `lo` to `hi` included.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    key: i128,
+    lo: i64,
+    hi: i64,
+}
+
+impl Run {
+    fn len(&self) -> u128 {
+        (self.hi as i128 - self.lo as i128) as u128 + 1
+    }
+}
+
+pub struct Line {
+    from: Point2,
+    to: Point2,
+}
+
+impl Line {
+    fn is_diagonal(&self) -> bool {
+        self.from.x != self.to.x && self.from.y != self.to.y
+    }
+    /// The family of the line, single points counting as horizontal.
+    fn family(&self) -> Family {
+        let (from, to) = (self.from, self.to);
+        if from.y == to.y {
+            Family::Horizontal
+        } else if from.x == to.x {
+            Family::Vertical
+        } else if (from.x < to.x) == (from.y < to.y) {
+            Family::Rising
+        } else {
+            Family::Falling
+        }
+    }
+    fn run(&self) -> Run {
+        let family = self.family();
+        let (from, to) = (family.position(self.from), family.position(self.to));
+        Run {
+            key: family.key(self.from),
+            lo: from.min(to),
+            hi: from.max(to),
+        }
+    }
+    /// Every point of the line, both ends included.
+    fn points(&self) -> impl Iterator<Item = Point2> {
+        let step = (self.to - self.from).signum();
+        let len = (self.to.x - self.from.x)
+            .abs()
+            .max((self.to.y - self.from.y).abs());
+        let from = self.from;
+        (0..=len).map(move |i| from + step * i)
+    }
+}
+
+impl FromStr for Line {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (st, en) = s
+            .split_once(" -> ")
+            .ok_or_else(|| AocError::new("expected `x1,y1 -> x2,y2`"))?;
+        let (from, to) = (parse_point2(s, st)?, parse_point2(s, en)?);
+        // In `i128`, lines may run across the whole range of an `i64`.
+        let dx = (to.x as i128 - from.x as i128).abs();
+        let dy = (to.y as i128 - from.y as i128).abs();
+        if dx != 0 && dy != 0 && dx != dy {
+            return Err(AocError::new(
+                "lines must be horizontal, vertical or at 45 degrees",
+            ));
+        }
+        Ok(Line { from, to })
+    }
+}
+
+/// Part 1 counts overlaps of horizontal and vertical lines, part 2 of diagonals too.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Line>, AocError> {
+        parse_lines(lines)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<Answer, AocError> {
+        Ok(BigUint::from(count_overlaps(lines, false)).into())
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<Answer, AocError> {
+        Ok(BigUint::from(count_overlaps(lines, true)).into())
+    }
+}
+
+/// Counts the points covered by at least two lines by drawing every point of
+/// every line. Slow on long lines, kept to check [`count_overlaps`] against.
+pub fn count_overlaps_raster(lines: &[Line], diagonals: bool) -> usize {
+    let mut floor = HashMap::new();
+    for line in lines {
+        if line.is_diagonal() && !diagonals {
+            continue;
+        }
+        for p in line.points() {
+            *floor.entry(p).or_insert(0) += 1;
+        }
+    }
+    floor.values().filter(|&v| *v >= 2).count()
+}
+
+/// The stretches covered by at least `depth` of `runs`, sorted by line and
+/// position, stretches next to each other merged.
+fn covered(runs: &[Run], depth: usize) -> Vec<Run> {
+    let mut events: Vec<(i128, i128, isize)> = runs
+        .iter()
+        .flat_map(|r| [(r.key, r.lo as i128, 1), (r.key, r.hi as i128 + 1, -1)])
+        .collect();
+    events.sort_unstable();
+    let mut res = Vec::new();
+    let mut count = 0;
+    let mut start = 0;
+    for group in events.chunk_by(|a, b| (a.0, a.1) == (b.0, b.1)) {
+        let (key, at) = (group[0].0, group[0].1);
+        let before = count;
+        count += group.iter().map(|e| e.2).sum::<isize>();
+        let depth = depth as isize;
+        if before < depth && count >= depth {
+            start = at;
+        } else if before >= depth && count < depth {
+            res.push(Run {
+                key,
+                lo: start as i64,
+                hi: (at - 1) as i64,
+            });
+        }
+    }
+    res
+}
+
+/// The runs of one family covering some points, found by their line.
+struct Cover {
+    family: Family,
+    /// Sorted as [`covered`] leaves them.
+    runs: Vec<Run>,
+    lines: HashMap<i128, Range<usize>>,
+}
+
+impl Cover {
+    fn new(family: Family, runs: Vec<Run>) -> Cover {
+        let mut lines = HashMap::new();
+        let mut start = 0;
+        for chunk in runs.chunk_by(|a, b| a.key == b.key) {
+            lines.insert(chunk[0].key, start..start + chunk.len());
+            start += chunk.len();
+        }
+        Cover {
+            family,
+            runs,
+            lines,
+        }
+    }
+
+    fn len(&self) -> u128 {
+        self.runs.iter().map(Run::len).sum()
+    }
+
+    fn contains(&self, p: Point2) -> bool {
+        let Some(range) = self.lines.get(&self.family.key(p)) else {
+            return false;
+        };
+        let runs = &self.runs[range.clone()];
+        let at = self.family.position(p);
+        let i = runs.partition_point(|r| r.hi < at);
+        runs.get(i).is_some_and(|r| r.lo <= at)
+    }
+}
+
+/// Visits the points where `flat` runs of one family cross `upright` runs of
+/// another.
+///
+/// In the coordinates `(u, v)`, the keys of the lines of `upright` and `flat`,
+/// the runs of `flat` are horizontal and those of `upright` vertical, so a
+/// sweep over `u` finds the runs of `flat` crossing each run of `upright` in
+/// a range of the runs it passes.
+fn crossings(
+    flat: Family,
+    flat_runs: &[Run],
+    upright: Family,
+    upright_runs: &[Run],
+    mut visit: impl FnMut(Point2),
+) {
+    // Ranges of the other key along a run, ordered.
+    let span = |family: Family, run: &Run, other: Family| {
+        let lo = other.key(family.point(run.key, run.lo));
+        let hi = other.key(family.point(run.key, run.hi));
+        (lo.min(hi), lo.max(hi))
+    };
+    // Runs of `flat` start before and end after the runs of `upright` at the
+    // same `u` are looked at.
+    const START: u8 = 0;
+    const CROSS: u8 = 1;
+    const END: u8 = 2;
+    let mut events = Vec::with_capacity(2 * flat_runs.len() + upright_runs.len());
+    for run in flat_runs {
+        let (lo, hi) = span(flat, run, upright);
+        events.push((lo, START, run.key, 0));
+        events.push((hi, END, run.key, 0));
+    }
+    for run in upright_runs {
+        let (lo, hi) = span(upright, run, flat);
+        events.push((run.key, CROSS, lo, hi));
+    }
+    events.sort_unstable();
+    // Runs of the same line never overlap, so the crossed lines are enough.
+    let mut active = BTreeSet::new();
+    for (u, kind, v, hi) in events {
+        match kind {
+            START => {
+                active.insert(v);
+            }
+            END => {
+                active.remove(&v);
+            }
+            _ => {
+                for &v in active.range(v..=hi) {
+                    if let Some(p) = flat.meet(v, upright, u) {
+                        visit(p);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Counts the points covered by at least two lines without drawing them.
+///
+/// Lines of the same family only overlap along the same line, which sorting
+/// their ends counts. Lines of different families cross in single points,
+/// found by a sweep for each pair of families; those are counted once more
+/// unless the first count had them, and once less for each extra family that
+/// had them.
+pub fn count_overlaps(lines: &[Line], diagonals: bool) -> u128 {
+    let families = if diagonals {
+        &Family::ALL[..]
+    } else {
+        &Family::ALL[..2]
+    };
+    let mut runs = vec![Vec::new(); Family::ALL.len()];
+    for line in lines {
+        let family = line.family();
+        if families.contains(&family) {
+            runs[family as usize].push(line.run());
+        }
+    }
+    let cover = |depth| -> Vec<Cover> {
+        (Family::ALL.iter().zip(&runs))
+            .map(|(&family, runs)| Cover::new(family, covered(runs, depth)))
+            .collect()
+    };
+    let (once, twice) = (cover(1), cover(2));
+    let mut count: u128 = twice.iter().map(Cover::len).sum();
+    for (j, &g) in families.iter().enumerate() {
+        for &f in &families[..j] {
+            crossings(f, &once[f as usize].runs, g, &once[g as usize].runs, |p| {
+                let mut doubled = 0;
+                for (k, &h) in families.iter().enumerate() {
+                    if h != f && h != g {
+                        if !once[h as usize].contains(p) {
+                            continue;
+                        }
+                        if k < j {
+                            // Points on more families are counted with the
+                            // first two.
+                            return;
+                        }
+                    }
+                    if twice[h as usize].contains(p) {
+                        doubled += 1;
+                    }
+                }
+                match doubled {
+                    0 => count += 1,
+                    // Counted once for each family already.
+                    n => count -= n - 1,
+                }
+            });
+        }
+    }
+    count
+}
@@ -0,0 +1,66 @@
+//! Day 6: Lanternfish, a growing school of fish.
+
+use crate::error::{first_line, parse_at, AocError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+struct School {
+    count_days: [u64; 9],
+}
+
+impl School {
+    fn new(v: &[u64]) -> School {
+        let mut count_days = [0; 9];
+        for i in v {
+            count_days[*i as usize] += 1;
+        }
+        School { count_days }
+    }
+    fn next_day(&mut self) {
+        let created = self.count_days[0];
+        for i in 0..8 {
+            self.count_days[i] = self.count_days[i + 1];
+        }
+        self.count_days[8] = created;
+        self.count_days[6] += created;
+    }
+    fn fish_count(&self) -> u64 {
+        self.count_days.iter().sum()
+    }
+}
+
+/// Number of lanternfish after `days` days, counting the fish by timer
+/// rather than one by one.
+pub fn fish_after(timers: &[u64], days: u32) -> u64 {
+    let mut school = School::new(timers);
+    for _ in 0..days {
+        school.next_day();
+    }
+    school.fish_count()
+}
+
+/// Number of lanternfish after 80 and 256 days.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<u64>;
+
+    fn parse(lines: &[String]) -> Result<Vec<u64>, AocError> {
+        let line = first_line(lines)?;
+        line.split(',')
+            .map(|s| match parse_at(line, s)? {
+                t @ 0..=8 => Ok(t),
+                _ => Err(AocError::new("timer must be between 0 and 8").at(line, s)),
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at_line(0))
+    }
+
+    fn part1(school: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(fish_after(school, 80).into())
+    }
+
+    fn part2(school: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(fish_after(school, 256).into())
+    }
+}
@@ -0,0 +1,75 @@
+//! Day 7: The Treachery of Whales, aligning the crab submarines.
+
+use crate::error::{first_line, parse_list, AocError};
+use crate::solution::{Answer, Solution};
+
+/// Fuel to move every crab to `new_position`, one unit per step.
+pub fn fuel_to_new_position(positions: &[i32], new_position: i32) -> i64 {
+    let mut fuel = 0;
+    for &p in positions {
+        fuel += (p as i64 - new_position as i64).abs();
+    }
+    fuel
+}
+
+/// Fuel to move every crab to `new_position`, each step costing one more
+/// than the previous one.
+pub fn fuel_to_new_position2(positions: &[i32], new_position: i32) -> i64 {
+    let mut fuel = 0;
+    for &p in positions {
+        let d = (p as i64 - new_position as i64).abs();
+        fuel += (1 + d) * d / 2;
+    }
+    fuel
+}
+
+/// Least fuel to align the crabs, at a constant rate and at an increasing one.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+
+    fn parse(lines: &[String]) -> Result<Vec<i32>, AocError> {
+        parse_list(first_line(lines)?).map_err(|e| e.at_line(0))
+    }
+
+    fn part1(positions: &Vec<i32>) -> Result<Answer, AocError> {
+        Ok(min_fuel_constant_rate(positions).into())
+    }
+
+    fn part2(positions: &Vec<i32>) -> Result<Answer, AocError> {
+        Ok(min_fuel_increasing_rate(positions).into())
+    }
+}
+
+/// Tries every position between the outermost crabs.
+pub fn min_fuel_exhaustive(positions: &[i32], fuel: fn(&[i32], i32) -> i64) -> i64 {
+    let min_position = *positions.iter().min().unwrap();
+    let max_position = *positions.iter().max().unwrap();
+    let mut min_fuel = fuel(positions, max_position);
+    for p in min_position..max_position {
+        let f = fuel(positions, p);
+        if f < min_fuel {
+            min_fuel = f;
+        }
+    }
+    min_fuel
+}
+
+/// The sum of distances is the least at the median.
+pub fn min_fuel_constant_rate(positions: &[i32]) -> i64 {
+    let mut sorted = positions.to_vec();
+    sorted.sort_unstable();
+    fuel_to_new_position(positions, sorted[sorted.len() / 2])
+}
+
+/// The sum of `d * (d + 1) / 2` is the least within half a step of the mean,
+/// the best whole position is next to that.
+pub fn min_fuel_increasing_rate(positions: &[i32]) -> i64 {
+    let sum: i64 = positions.iter().map(|&p| p as i64).sum();
+    let floor = sum.div_euclid(positions.len() as i64) as i32;
+    (floor - 1..=floor + 2)
+        .map(|p| fuel_to_new_position2(positions, p))
+        .min()
+        .unwrap()
+}
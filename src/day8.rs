@@ -0,0 +1,175 @@
+//! Day 8: Seven Segment Search, decoding scrambled displays.
+
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// An entry of the notes, its output decoded from the ten patterns.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    digits: Vec<u32>,
+}
+
+/// Part 1 counts the easy digits, part 2 sums the decoded output values.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+
+    fn parse(lines: &[String]) -> Result<Vec<Entry>, AocError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| decode_entry(line).map_err(|e| e.at_line(i)))
+            .collect()
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<Answer, AocError> {
+        Ok(task1(entries).into())
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<Answer, AocError> {
+        Ok(task2(entries).into())
+    }
+}
+
+/// Checks that an entry consists of ten patterns and four output digits.
+fn check_entry(line: &str) -> Result<(), AocError> {
+    let (patterns, output) = line
+        .split_once(" | ")
+        .ok_or_else(|| AocError::new("expected `<patterns> | <output>`"))?;
+    if let Some(col) = line.find(|c: char| !matches!(c, 'a'..='g' | ' ' | '|')) {
+        return Err(AocError::new("expected segments a-g").at_column(col));
+    }
+    if patterns.split(' ').count() != 10 {
+        return Err(AocError::new("expected 10 patterns"));
+    }
+    if output.split(' ').count() != 4 {
+        return Err(AocError::new("expected 4 output digits").at(line, output));
+    }
+    Ok(())
+}
+
+fn decode_entry(line: &str) -> Result<Entry, AocError> {
+    check_entry(line)?;
+    let (patterns, output) = line.split_once(" | ").unwrap();
+    let dict = decode_patterns(patterns)?;
+    let digits = output
+        .split(' ')
+        .map(|digit| match dict.get(&Signal::new(digit)) {
+            Some(c) => Ok(c.to_digit(10).unwrap()),
+            None => Err(
+                AocError::new(format!("output digit {:?} is none of the patterns", digit))
+                    .at(line, digit),
+            ),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Entry { digits })
+}
+
+fn task1(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| &entry.digits)
+        .filter(|digit| matches!(digit, 1 | 4 | 7 | 8))
+        .count()
+}
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+struct Signal {
+    chars: String,
+}
+
+impl Signal {
+    fn new(s: &str) -> Signal {
+        let mut chars: Vec<char> = s.chars().collect();
+        chars.sort();
+        let chars = String::from_iter(chars);
+        Signal { chars }
+    }
+}
+
+/// The pattern found for digit `c`, failing when none was.
+fn found<'a>(
+    c2s: &HashMap<char, &'a HashSet<char>>,
+    c: char,
+) -> Result<&'a HashSet<char>, AocError> {
+    c2s.get(&c)
+        .copied()
+        .ok_or_else(|| AocError::new(format!("the patterns don't identify digit {}", c)))
+}
+
+fn decode_patterns(patterns: &str) -> Result<HashMap<Signal, char>, AocError> {
+    let mut c2s = HashMap::new();
+    let patterns: &Vec<HashSet<char>> = &patterns
+        .split(' ')
+        .map(|s| HashSet::<char>::from_iter(s.chars()))
+        .collect();
+    for pattern in patterns {
+        let c = match pattern.len() {
+            2 => '1',
+            3 => '7',
+            4 => '4',
+            7 => '8',
+            _ => continue,
+        };
+        c2s.insert(c, pattern);
+    }
+
+    // find 6: 6.len() == 6; (1-6).len() == 1
+    let one = found(&c2s, '1')?;
+    for pattern in patterns {
+        if pattern.len() == 6 && one.difference(pattern).count() == 1 {
+            c2s.insert('6', pattern);
+            break;
+        }
+    }
+
+    // find 3: 3.len() == 5; (1 - 3).len() == 0
+    for pattern in patterns {
+        if pattern.len() == 5 && one.difference(pattern).count() == 0 {
+            c2s.insert('3', pattern);
+            break;
+        }
+    }
+
+    let three = found(&c2s, '3')?;
+    let six = found(&c2s, '6')?;
+    // find 9, 0, 5, 2
+    for pattern in patterns {
+        if pattern.len() == 6 && pattern != six {
+            let c = match pattern.difference(three).count() {
+                1 => '9',
+                2 => '0',
+                _ => continue,
+            };
+            c2s.insert(c, pattern);
+        } else if pattern.len() == 5 && pattern != three {
+            let c = match pattern.difference(six).count() {
+                0 => '5',
+                1 => '2',
+                _ => continue,
+            };
+            c2s.insert(c, pattern);
+        }
+    }
+    for c in ['0', '2', '5', '8', '9'] {
+        found(&c2s, c)?;
+    }
+    let mut res = HashMap::new();
+    for (c, pattern) in c2s {
+        let s = String::from_iter(pattern);
+        res.insert(Signal::new(&s), c);
+    }
+    if res.len() != 10 {
+        return Err(AocError::new("the patterns don't tell all digits apart"));
+    }
+    Ok(res)
+}
+
+fn task2(entries: &[Entry]) -> u64 {
+    entries
+        .iter()
+        .map(|entry| entry.digits.iter().fold(0, |n, &d| n * 10 + d as u64))
+        .sum()
+}
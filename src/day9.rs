@@ -0,0 +1,114 @@
+//! Day 9: Smoke Basin, low points and basins of a height map.
+
+use crate::error::AocError;
+use crate::frames::{Frame, Frames, Rgb};
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use std::error::Error;
+use std::path::Path;
+
+/// Colours told apart easily, for neighbouring basins.
+const PALETTE: [Rgb; 8] = [
+    [230, 25, 75],
+    [60, 180, 75],
+    [255, 225, 25],
+    [0, 130, 200],
+    [245, 130, 48],
+    [145, 30, 180],
+    [70, 240, 240],
+    [240, 50, 230],
+];
+
+/// Part 1 sums the risk levels of the low points, part 2 multiplies the three largest basins.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u32>;
+
+    fn parse(lines: &[String]) -> Result<Grid<u32>, AocError> {
+        Grid::parse_digits(lines)
+    }
+
+    fn part1(height: &Grid<u32>) -> Result<Answer, AocError> {
+        Ok(task1(height).into())
+    }
+
+    fn part2(height: &Grid<u32>) -> Result<Answer, AocError> {
+        Ok(task2(height.clone()).into())
+    }
+}
+
+fn task1(height: &Grid<u32>) -> u32 {
+    let mut s = 0;
+    for ((x, y), &v) in height.iter() {
+        if height.neighbors4(x, y).all(|n| height[n] > v) {
+            s += v + 1;
+        }
+    }
+    s
+}
+
+fn discover_basin(height: &mut Grid<u32>, x: usize, y: usize) -> u32 {
+    let mut s = 1;
+    height[(x, y)] = 10;
+    for (nx, ny) in height.neighbors4(x, y) {
+        if height[(nx, ny)] < 9 {
+            s += discover_basin(height, nx, ny);
+        }
+    }
+    s
+}
+
+/// Product of the sizes of the three largest basins, or of all of them on
+/// maps with fewer.
+fn task2(mut height: Grid<u32>) -> u32 {
+    let mut s = Vec::new();
+    for (x, y) in height.positions() {
+        if height[(x, y)] < 9 {
+            s.push(discover_basin(&mut height, x, y));
+        }
+    }
+    s.sort_by(|a, b| b.cmp(a));
+    s.iter().take(3).product()
+}
+
+/// Number of the basin of every cell, `None` for the walls of 9.
+fn basins(height: &Grid<u32>) -> Grid<Option<usize>> {
+    let mut basin = Grid::new(height.width(), height.height(), None);
+    let mut count = 0;
+    for start in height.positions() {
+        if height[start] == 9 || basin[start].is_some() {
+            continue;
+        }
+        let mut todo = vec![start];
+        basin[start] = Some(count);
+        while let Some((x, y)) = todo.pop() {
+            for n in height.neighbors4(x, y) {
+                if height[n] < 9 && basin[n].is_none() {
+                    basin[n] = Some(count);
+                    todo.push(n);
+                }
+            }
+        }
+        count += 1;
+    }
+    basin
+}
+
+/// Writes the height map with every basin in its own colour, brighter where
+/// it is lower, and the low points in white. Returns the number of frames.
+pub fn frames(lines: &[String], dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let height = Grid::parse_digits(lines)?;
+    let basin = basins(&height);
+    let mut frames = Frames::new(dir, "day9")?;
+    frames.push(&Frame::color(&height, |(x, y), &h| {
+        if height.neighbors4(x, y).all(|n| height[n] > h) {
+            return [255, 255, 255];
+        }
+        match basin[(x, y)] {
+            Some(b) => PALETTE[b % PALETTE.len()].map(|c| (c as u32 * (12 - h) / 12) as u8),
+            None => [0, 0, 0],
+        }
+    }))?;
+    Ok(frames.count())
+}
@@ -0,0 +1,133 @@
+//! Error type shared by all puzzle parsers.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Describes what is wrong with a puzzle input and where.
+///
+/// Parsers of a single line only know the column, the line number and the
+/// day are filled in by the callers as the error bubbles up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub day: Option<u32>,
+    /// 1-based line number.
+    pub line: Option<usize>,
+    /// 1-based column, counted in bytes.
+    pub column: Option<usize>,
+    pub reason: String,
+}
+
+impl AocError {
+    pub fn new<S: Into<String>>(reason: S) -> AocError {
+        AocError {
+            day: None,
+            line: None,
+            column: None,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn in_day(mut self, day: u32) -> AocError {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Sets the line from a 0-based index, as produced by `enumerate()`.
+    pub fn at_line(mut self, index: usize) -> AocError {
+        self.line.get_or_insert(index + 1);
+        self
+    }
+
+    /// Shifts the line of an error found while parsing `lines[skipped..]`.
+    pub fn after_lines(mut self, skipped: usize) -> AocError {
+        if let Some(line) = &mut self.line {
+            *line += skipped;
+        }
+        self
+    }
+
+    /// Sets the column from a 0-based byte offset.
+    pub fn at_column(mut self, offset: usize) -> AocError {
+        self.column.get_or_insert(offset + 1);
+        self
+    }
+
+    /// Sets the column to the position of `part`, which must be a slice of `line`.
+    pub fn at(self, line: &str, part: &str) -> AocError {
+        self.at_column(offset_in(line, part))
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}: ", day)?;
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for AocError {}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        AocError::new(e.to_string())
+    }
+}
+
+fn offset_in(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() {
+        offset
+    } else {
+        0
+    }
+}
+
+/// Parses `part` of `line`, pointing the error at its column.
+pub fn parse_at<T>(line: &str, part: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.trim()
+        .parse()
+        .map_err(|e: T::Err| AocError::new(format!("bad value {:?}: {}", part, e)).at(line, part))
+}
+
+/// Parses every line, adding the line number to errors.
+pub fn parse_lines<T>(lines: &[String]) -> Result<Vec<T>, AocError>
+where
+    T: FromStr<Err = AocError>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: AocError| e.at_line(i)))
+        .collect()
+}
+
+/// Returns the first line, failing on empty input.
+pub fn first_line(lines: &[String]) -> Result<&str, AocError> {
+    lines
+        .first()
+        .map(|s| s.as_str())
+        .ok_or_else(|| AocError::new("empty input"))
+}
+
+/// Parses a comma separated list of numbers.
+pub fn parse_list<T>(line: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    line.split(',').map(|s| parse_at(line, s)).collect()
+}
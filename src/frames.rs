@@ -0,0 +1,144 @@
+//! Pictures of the map puzzles as binary PGM (grey) and PPM (colour) images,
+//! which most image viewers open.
+//!
+//! Simulations write one numbered frame per step, which tools like ffmpeg
+//! turn into an animation: `ffmpeg -i day11_%04d.pgm day11.gif`.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::grid::Grid;
+
+/// Usage of the `--frames` option of the per-day binaries.
+pub const USAGE: &str = "[--frames DIR] ";
+
+/// Frames are scaled up until their larger side has at least this many pixels.
+const MIN_SIDE: usize = 400;
+
+pub type Rgb = [u8; 3];
+
+/// An image with one pixel per cell of the grid it was drawn from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Grey(Grid<u8>),
+    Color(Grid<Rgb>),
+}
+
+impl Frame {
+    /// Draws `grid` in shades of grey, 0 being black.
+    pub fn grey<T, F>(grid: &Grid<T>, mut shade: F) -> Frame
+    where
+        F: FnMut((usize, usize), &T) -> u8,
+    {
+        Frame::Grey(Grid::from_fn(grid.width(), grid.height(), |x, y| {
+            shade((x, y), &grid[(x, y)])
+        }))
+    }
+
+    /// Draws `grid` in colour.
+    pub fn color<T, F>(grid: &Grid<T>, mut color: F) -> Frame
+    where
+        F: FnMut((usize, usize), &T) -> Rgb,
+    {
+        Frame::Color(Grid::from_fn(grid.width(), grid.height(), |x, y| {
+            color((x, y), &grid[(x, y)])
+        }))
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Frame::Grey(g) => g.width(),
+            Frame::Color(g) => g.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        match self {
+            Frame::Grey(g) => g.height(),
+            Frame::Color(g) => g.height(),
+        }
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Frame {
+        fn scale<T: Copy>(g: &Grid<T>, factor: usize) -> Grid<T> {
+            Grid::from_fn(g.width() * factor, g.height() * factor, |x, y| {
+                g[(x / factor, y / factor)]
+            })
+        }
+        match self {
+            Frame::Grey(g) => Frame::Grey(scale(g, factor)),
+            Frame::Color(g) => Frame::Color(scale(g, factor)),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Frame::Grey(_) => "pgm",
+            Frame::Color(_) => "ppm",
+        }
+    }
+
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let (magic, bytes) = match self {
+            Frame::Grey(g) => ("P5", g.values().copied().collect::<Vec<_>>()),
+            Frame::Color(g) => ("P6", g.values().flatten().copied().collect()),
+        };
+        write!(out, "{}\n{} {}\n255\n", magic, self.width(), self.height())?;
+        out.write_all(&bytes)
+    }
+}
+
+/// Writes frames numbered from 0 to a directory, named like `day11_0042.pgm`.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    name: String,
+    count: usize,
+}
+
+impl Frames {
+    /// Creates `dir` if it doesn't exist yet.
+    pub fn new(dir: &Path, name: &str) -> io::Result<Frames> {
+        fs::create_dir_all(dir)?;
+        Ok(Frames {
+            dir: dir.to_path_buf(),
+            name: name.to_string(),
+            count: 0,
+        })
+    }
+
+    /// Writes the next frame, scaled up so that small maps stay visible.
+    pub fn push(&mut self, frame: &Frame) -> io::Result<PathBuf> {
+        let factor = (MIN_SIDE / frame.width().max(frame.height()).max(1)).max(1);
+        let path = self.dir.join(format!(
+            "{}_{:04}.{}",
+            self.name,
+            self.count,
+            frame.extension()
+        ));
+        let mut out = BufWriter::new(File::create(&path)?);
+        frame.scaled(factor).write_to(&mut out)?;
+        out.flush()?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// Number of frames written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Takes `--frames DIR` for a per-day binary, see [`crate::get_lines_with`].
+pub fn parse_option<I>(arg: &str, rest: &mut I, dir: &mut Option<PathBuf>) -> Result<bool, String>
+where
+    I: Iterator<Item = String>,
+{
+    if arg != "--frames" {
+        return Ok(false);
+    }
+    *dir = Some(rest.next().ok_or("--frames needs a directory")?.into());
+    Ok(true)
+}
@@ -0,0 +1,721 @@
+//! Seeded generators of random puzzle inputs, to stress the solvers with
+//! inputs bigger than the bundled examples.
+//!
+//! The same day, seed and size always give the same input. Some generators
+//! build their input around a known solution and report its answers too.
+
+use crate::bits::BitWriter;
+use crate::day11::EnergyLevelMap;
+use crate::day24;
+use crate::day25::Herds;
+use crate::error::AocError;
+use crate::point::{Bounds3, Point3, Rotation};
+use crate::solution::{Answer, Solution};
+use crate::{day11, day25};
+use std::collections::{BTreeSet, HashSet};
+
+/// Small splitmix64 generator, good enough for test inputs and stable
+/// across platforms and releases.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// A generated input, with the answers when they are known by construction.
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub lines: Vec<String>,
+    pub answers: Option<(Answer, Answer)>,
+}
+
+impl Generated {
+    fn lines(lines: Vec<String>) -> Generated {
+        Generated {
+            lines,
+            answers: None,
+        }
+    }
+}
+
+type Generator = fn(&mut Rng, usize) -> Result<Generated, AocError>;
+
+/// Generator of each day and its default size. What the size counts depends
+/// on the day: lines, boards, the side of a map... Days 21 and 23 ignore it.
+const GENERATORS: [(Generator, usize); 25] = [
+    (day1, 2000),
+    (day2, 1000),
+    (day3, 1000),
+    (day4, 100),
+    (day5, 500),
+    (day6, 300),
+    (day7, 1000),
+    (day8, 200),
+    (day9, 100),
+    (day10, 100),
+    (day11, 10),
+    (day12, 8),
+    (day13, 800),
+    (day14, 20),
+    (day15, 100),
+    (day16, 10),
+    (day17, 100),
+    (day18, 100),
+    (day19, 20),
+    (day20, 100),
+    (day21, 0),
+    (day22, 420),
+    (day23, 0),
+    (day24, 0),
+    (day25, 100),
+];
+
+/// Generates an input for `day` from `seed`. `size` defaults to something
+/// close to a real puzzle input.
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Result<Generated, AocError> {
+    let (generator, default) = day
+        .checked_sub(1)
+        .and_then(|i| GENERATORS.get(i as usize))
+        .ok_or_else(|| AocError::new(format!("no generator for day {}", day)))?;
+    let mut rng = Rng::new(seed);
+    generator(&mut rng, size.unwrap_or(*default)).map_err(|e| e.in_day(day))
+}
+
+fn join<T: ToString>(items: impl IntoIterator<Item = T>, sep: &str) -> String {
+    items
+        .into_iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// `count` distinct numbers below `n`, in random order.
+fn sample(rng: &mut Rng, n: u32, count: usize) -> Vec<u32> {
+    let mut all: Vec<u32> = (0..n).collect();
+    rng.shuffle(&mut all);
+    all.truncate(count);
+    all
+}
+
+fn digit_map(
+    rng: &mut Rng,
+    side: usize,
+    mut digit: impl FnMut(&mut Rng, usize, usize) -> u64,
+) -> Vec<String> {
+    (0..side)
+        .map(|y| join((0..side).map(|x| digit(rng, x, y)), ""))
+        .collect()
+}
+
+fn day1(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let mut depth = rng.range(100, 200);
+    let lines = (0..size)
+        .map(|_| {
+            depth = (depth + rng.range(-10, 30)).max(0);
+            depth.to_string()
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day2(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let mut depth = 0;
+    let lines = (0..size)
+        .map(|_| {
+            let units = rng.range(1, 9);
+            match rng.below(3) {
+                0 => format!("forward {}", units),
+                1 if depth >= units => {
+                    depth -= units;
+                    format!("up {}", units)
+                }
+                _ => {
+                    depth += units;
+                    format!("down {}", units)
+                }
+            }
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day3(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = sample(rng, 1 << 12, size.max(1))
+        .into_iter()
+        .map(|n| format!("{:012b}", n))
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day4(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let mut lines = vec![join(sample(rng, 100, 100), ",")];
+    for _ in 0..size.max(1) {
+        lines.push(String::new());
+        let numbers = sample(rng, 100, 25);
+        for row in numbers.chunks(5) {
+            lines.push(join(row.iter().map(|n| format!("{:>2}", n)), " "));
+        }
+    }
+    Ok(Generated::lines(lines))
+}
+
+fn day5(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = (0..size)
+        .map(|_| {
+            let (x1, y1) = (rng.range(0, 999), rng.range(0, 999));
+            let len = rng.range(1, 300);
+            let (dx, dy) = match rng.below(3) {
+                0 => (*rng.choose(&[-1, 1]), 0),
+                1 => (0, *rng.choose(&[-1, 1])),
+                _ => (*rng.choose(&[-1, 1]), *rng.choose(&[-1, 1])),
+            };
+            // Shorten the line so that it stays on the map.
+            let room = |start: i64, d: i64| match d {
+                1 => 999 - start,
+                -1 => start,
+                _ => len,
+            };
+            let len = len.min(room(x1, dx)).min(room(y1, dy));
+            format!("{},{} -> {},{}", x1, y1, x1 + dx * len, y1 + dy * len)
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day6(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let fish = (0..size.max(1)).map(|_| rng.range(1, 5));
+    Ok(Generated::lines(vec![join(fish.collect::<Vec<_>>(), ",")]))
+}
+
+fn day7(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let crabs = (0..size.max(1)).map(|_| rng.range(0, 1500));
+    Ok(Generated::lines(vec![join(crabs.collect::<Vec<_>>(), ",")]))
+}
+
+fn day8(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    let lines = (0..size)
+        .map(|_| {
+            let mut wiring: Vec<char> = "abcdefg".chars().collect();
+            rng.shuffle(&mut wiring);
+            let scramble = |rng: &mut Rng, digit: &str| {
+                let mut segments: Vec<char> =
+                    digit.bytes().map(|s| wiring[(s - b'a') as usize]).collect();
+                rng.shuffle(&mut segments);
+                segments.into_iter().collect::<String>()
+            };
+            let mut patterns: Vec<_> = DIGITS.iter().map(|d| scramble(rng, d)).collect();
+            let output: Vec<_> = (0..4)
+                .map(|_| {
+                    let digit = DIGITS[rng.below(10) as usize];
+                    scramble(rng, digit)
+                })
+                .collect();
+            rng.shuffle(&mut patterns);
+            format!("{} | {}", patterns.join(" "), output.join(" "))
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day9(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    // Walls of 9 split the map into basins, a few more 9 give them shapes.
+    let lines = digit_map(rng, size.max(10), |rng, x, y| {
+        if x % 8 == 7 || y % 8 == 7 || rng.chance(0.15) {
+            9
+        } else {
+            rng.below(9)
+        }
+    });
+    Ok(Generated::lines(lines))
+}
+
+fn day10(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let lines = (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            let mut stack = Vec::new();
+            for _ in 0..rng.range(10, 60) {
+                match stack.pop() {
+                    Some(close) if rng.chance(0.45) => line.push(close),
+                    top => {
+                        stack.extend(top);
+                        let (open, close) = *rng.choose(&PAIRS);
+                        line.push(open);
+                        stack.push(close);
+                    }
+                }
+            }
+            if rng.chance(0.5) {
+                // Corrupted: close with the wrong character.
+                let expected = stack.last().copied();
+                let wrong: Vec<_> = PAIRS
+                    .iter()
+                    .map(|&(_, close)| close)
+                    .filter(|&c| Some(c) != expected)
+                    .collect();
+                line.push(*rng.choose(&wrong));
+            } else if stack.is_empty() {
+                // Incomplete: leave at least one chunk open.
+                line.push(rng.choose(&PAIRS).0);
+            }
+            line
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+/// Steps a generated map of day 11 or 25 may take before it is rejected.
+const STEP_LIMIT: usize = 10_000;
+/// Maps drawn before giving up on one that settles within [`STEP_LIMIT`].
+const ATTEMPTS: usize = 100;
+
+fn day11(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let side = size.max(2);
+    for _ in 0..ATTEMPTS {
+        let lines = digit_map(rng, side, |rng, _, _| rng.below(10));
+        let mut map: EnergyLevelMap = day11::Day11::parse(&lines)?;
+        if (0..STEP_LIMIT).any(|_| map.step() as usize == side * side) {
+            return Ok(Generated::lines(lines));
+        }
+    }
+    Err(AocError::new(format!(
+        "no {0}x{0} map flashed all at once within {1} steps",
+        side, STEP_LIMIT
+    )))
+}
+
+fn cave_names(rng: &mut Rng, count: usize, letters: &str) -> Vec<String> {
+    let letters: Vec<char> = letters.chars().collect();
+    let mut names = BTreeSet::new();
+    while names.len() < count {
+        names.insert((0..2).map(|_| *rng.choose(&letters)).collect::<String>());
+    }
+    let mut names: Vec<_> = names.into_iter().collect();
+    rng.shuffle(&mut names);
+    names
+}
+
+fn day12(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let small = cave_names(rng, size.clamp(1, 600), "abcdefghijklmnopqrstuvwxyz");
+    let big = cave_names(rng, (size / 3).clamp(1, 600), "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    let mut edges = BTreeSet::new();
+    let mut connect = |a: &str, b: &str| {
+        if a != b && !edges.contains(&(b.to_string(), a.to_string())) {
+            edges.insert((a.to_string(), b.to_string()));
+        }
+    };
+    let caves: Vec<_> = small.iter().chain(&big).collect();
+    for end in ["start", "end"] {
+        for _ in 0..rng.range(1, 2) {
+            connect(end, rng.choose(&caves).as_str());
+        }
+    }
+    for cave in &small {
+        for _ in 0..rng.range(1, 2) {
+            connect(cave, rng.choose(&caves).as_str());
+        }
+    }
+    // Two connected big caves would make the number of paths infinite.
+    for cave in &big {
+        for _ in 0..rng.range(1, 3) {
+            connect(cave, rng.choose(&small).as_str());
+        }
+    }
+    let mut lines: Vec<_> = edges
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", a, b))
+        .collect();
+    rng.shuffle(&mut lines);
+    Ok(Generated::lines(lines))
+}
+
+fn day13(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    // Each fold halves the paper exactly, starting from the final size.
+    let fold_lines = |rng: &mut Rng, side: i64| {
+        let mut lines = vec![side];
+        for _ in 0..rng.range(3, 5) {
+            lines.push(2 * lines.last().unwrap() + 1);
+        }
+        let side = lines.pop().unwrap();
+        (lines, side)
+    };
+    let (final_width, final_height) = (rng.range(5, 40), rng.range(6, 8));
+    let (mut xs, width) = fold_lines(rng, final_width);
+    let (mut ys, height) = fold_lines(rng, final_height);
+    let mut dots = BTreeSet::new();
+    for _ in 0..size.max(1) {
+        let (x, y) = (rng.range(0, width - 1), rng.range(0, height - 1));
+        if !xs.contains(&x) && !ys.contains(&y) {
+            dots.insert((x, y));
+        }
+    }
+    let mut lines: Vec<_> = dots.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    rng.shuffle(&mut lines);
+    lines.push(String::new());
+    while !xs.is_empty() || !ys.is_empty() {
+        let along_x = ys.is_empty() || (!xs.is_empty() && rng.chance(0.5));
+        let (axis, at) = if along_x {
+            ('x', xs.pop().unwrap())
+        } else {
+            ('y', ys.pop().unwrap())
+        };
+        lines.push(format!("fold along {}={}", axis, at));
+    }
+    Ok(Generated::lines(lines))
+}
+
+fn day14(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let mut alphabet: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut alphabet);
+    let letters = &alphabet[..rng.range(4, 10) as usize];
+    let template: String = (0..size.max(2)).map(|_| *rng.choose(letters)).collect();
+    let mut lines = vec![template, String::new()];
+    for a in letters {
+        for b in letters {
+            lines.push(format!("{}{} -> {}", a, b, rng.choose(letters)));
+        }
+    }
+    Ok(Generated::lines(lines))
+}
+
+fn day15(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = digit_map(rng, size.max(2), |rng, _, _| rng.range(1, 9) as u64);
+    Ok(Generated::lines(lines))
+}
+
+/// Literal values and products stay below this, so that every operator
+/// packet fits in an `i64`.
+const BITS_VALUE_LIMIT: u64 = 1 << 48;
+
+/// A random BITS packet nested at most `depth` levels deep, with its value.
+fn bits_packet(rng: &mut Rng, depth: u32) -> (BitWriter, u64) {
+    let mut bits = BitWriter::new();
+    bits.write(rng.below(8), 3);
+    if depth == 0 || rng.chance(0.3) {
+        let width = rng.range(4, 20);
+        let value = rng.below(1 << width);
+        bits.write(4, 3);
+        let groups = (64 - value.leading_zeros() as usize).max(1).div_ceil(4);
+        for g in (0..groups).rev() {
+            bits.write((g > 0) as u64 * 16 + ((value >> (4 * g)) & 15), 5);
+        }
+        return (bits, value);
+    }
+    let mut type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if type_id >= 5 { 2 } else { rng.range(1, 4) };
+    let children: Vec<_> = (0..count).map(|_| bits_packet(rng, depth - 1)).collect();
+    let values = children.iter().map(|&(_, v)| v);
+    let product = values.clone().try_fold(1u64, |p, v| {
+        p.checked_mul(v).filter(|&p| p < BITS_VALUE_LIMIT)
+    });
+    if type_id == 1 && product.is_none() {
+        type_id = 2;
+    }
+    let value = match type_id {
+        0 => values.sum(),
+        1 => product.unwrap(),
+        2 => values.min().unwrap(),
+        3 => values.max().unwrap(),
+        5 => (children[0].1 > children[1].1) as u64,
+        6 => (children[0].1 < children[1].1) as u64,
+        _ => (children[0].1 == children[1].1) as u64,
+    };
+    bits.write(type_id, 3);
+    let mut body = BitWriter::new();
+    for (child, _) in &children {
+        body.append(child);
+    }
+    if body.len() < 1 << 15 && rng.chance(0.5) {
+        bits.write(0, 1);
+        bits.write(body.len() as u64, 15);
+    } else {
+        bits.write(1, 1);
+        bits.write(count as u64, 11);
+    }
+    bits.append(&body);
+    // Sums may go past the limit, which then keeps their products out.
+    (bits, value.min(BITS_VALUE_LIMIT))
+}
+
+fn day16(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = (0..size)
+        .map(|_| {
+            let depth = rng.range(1, 6) as u32;
+            let (bits, _) = bits_packet(rng, depth);
+            hex::encode_upper(bits.as_bytes())
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day17(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let size = size.max(4) as i64;
+    let x1 = rng.range(size, 2 * size);
+    let x2 = x1 + rng.range(size / 4, size / 2);
+    let y2 = -rng.range(size / 4 + 2, size);
+    let y1 = y2 - rng.range(size / 4, size / 2);
+    Ok(Generated::lines(vec![format!(
+        "target area: x={}..{}, y={}..{}",
+        x1, x2, y1, y2
+    )]))
+}
+
+/// A snailfish number at nesting `depth`, which only reduced numbers reach
+/// with pairs of regular numbers.
+fn snailfish(rng: &mut Rng, depth: u32) -> String {
+    let element = |rng: &mut Rng| {
+        if depth < 4 && rng.chance(0.6) {
+            snailfish(rng, depth + 1)
+        } else {
+            rng.below(10).to_string()
+        }
+    };
+    let left = element(rng);
+    let right = element(rng);
+    format!("[{},{}]", left, right)
+}
+
+fn day18(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = (0..size.max(1)).map(|_| snailfish(rng, 1)).collect();
+    Ok(Generated::lines(lines))
+}
+
+/// Scanners see beacons up to this far on each axis.
+const SCANNER_RANGE: i64 = 1000;
+
+fn random_in(rng: &mut Rng, b: Bounds3) -> Point3 {
+    let x = rng.range(b.min.x, b.max.x);
+    let y = rng.range(b.min.y, b.max.y);
+    Point3::new(x, y, rng.range(b.min.z, b.max.z))
+}
+
+fn day19(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    // Every scanner overlaps with an earlier one, sharing at least 12
+    // beacons with it, so all of them can be placed.
+    let range = Point3::new(SCANNER_RANGE, SCANNER_RANGE, SCANNER_RANGE);
+    let seen_from = |pos: Point3| Bounds3 {
+        min: pos - range,
+        max: pos + range,
+    };
+    let mut scanners = vec![Point3::ORIGIN];
+    let mut beacons = HashSet::new();
+    for i in 0..size.max(1) {
+        let pos = if i == 0 {
+            Point3::ORIGIN
+        } else {
+            let parent = *rng.choose(&scanners);
+            let x = rng.range(-900, 900);
+            let y = rng.range(-900, 900);
+            let pos = parent + Point3::new(x, y, rng.range(-900, 900));
+            let shared_area = seen_from(pos).intersection(&seen_from(parent)).unwrap();
+            let mut shared = 0;
+            while shared < 12 + rng.below(3) {
+                if beacons.insert(random_in(rng, shared_area)) {
+                    shared += 1;
+                }
+            }
+            scanners.push(pos);
+            pos
+        };
+        for _ in 0..rng.range(10, 20) {
+            beacons.insert(random_in(rng, seen_from(pos)));
+        }
+    }
+    let mut beacons: Vec<_> = beacons.into_iter().collect();
+    beacons.sort_unstable();
+    let rotations = Rotation::all();
+    let mut lines = Vec::new();
+    for (i, &pos) in scanners.iter().enumerate() {
+        if i > 0 {
+            lines.push(String::new());
+        }
+        lines.push(format!("--- scanner {} ---", i));
+        let rotation = if i == 0 {
+            Rotation::IDENTITY
+        } else {
+            *rng.choose(&rotations)
+        };
+        let mut seen: Vec<_> = beacons
+            .iter()
+            .filter(|&&b| seen_from(pos).contains(b))
+            .map(|&b| b - pos)
+            .collect();
+        rng.shuffle(&mut seen);
+        for b in seen {
+            lines.push(rotation.apply(b).to_string());
+        }
+    }
+    let farthest = scanners
+        .iter()
+        .flat_map(|a| scanners.iter().map(move |b| a.manhattan(*b)))
+        .max()
+        .unwrap_or(0);
+    Ok(Generated {
+        lines,
+        answers: Some((beacons.len().into(), Answer::Int(farthest))),
+    })
+}
+
+fn day20(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    // Lighting the infinite dark background must dim it again on the next
+    // step, otherwise there are infinitely many light pixels.
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let mut lines = vec![algorithm.into_iter().collect(), String::new()];
+    let side = size.max(1);
+    lines.extend((0..side).map(|_| (0..side).map(|_| pixel(rng)).collect()));
+    Ok(Generated::lines(lines))
+}
+
+fn day21(rng: &mut Rng, _size: usize) -> Result<Generated, AocError> {
+    let lines = (1..=2)
+        .map(|p| format!("Player {} starting position: {}", p, rng.range(1, 10)))
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day22(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let lines = (0..size.max(1))
+        .map(|i| {
+            // The first steps stay in the initialization region, like in the
+            // real inputs.
+            let (lo, hi, len) = if i < 20 {
+                (-50, 50, 30)
+            } else {
+                (-100_000, 100_000, 40_000)
+            };
+            let range = |rng: &mut Rng| {
+                let start = rng.range(lo, hi);
+                (start, (start + rng.range(len / 10, len)).min(hi))
+            };
+            let (x, y, z) = (range(rng), range(rng), range(rng));
+            let state = if i == 0 || rng.chance(0.7) {
+                "on"
+            } else {
+                "off"
+            };
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                state, x.0, x.1, y.0, y.1, z.0, z.1
+            )
+        })
+        .collect();
+    Ok(Generated::lines(lines))
+}
+
+fn day23(rng: &mut Rng, _size: usize) -> Result<Generated, AocError> {
+    let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[char]| join(row, "#");
+    Ok(Generated::lines(vec![
+        "#############".to_string(),
+        "#...........#".to_string(),
+        format!("###{}###", row(&amphipods[..4])),
+        format!("  #{}#", row(&amphipods[4..])),
+        "  #########".to_string(),
+    ]))
+}
+
+fn day24(rng: &mut Rng, _size: usize) -> Result<Generated, AocError> {
+    // Pushes and pops form a balanced sequence, each pop requiring its digit
+    // to be the pushed one plus a difference that leaves room for a digit.
+    let mut lines = Vec::new();
+    let mut stack = Vec::new();
+    let (mut largest, mut smallest) = ([0; day24::DIGITS], [0; day24::DIGITS]);
+    for i in 0..day24::DIGITS {
+        let left = day24::DIGITS - i;
+        let push = stack.is_empty() || (stack.len() + 2 <= left && rng.chance(0.5));
+        let constants = if push {
+            let offset = rng.range(1, 16);
+            stack.push((i, offset));
+            [1, rng.range(10, 16), offset]
+        } else {
+            let (first, offset) = stack.pop().unwrap();
+            let diff = rng.range(-8, 8);
+            largest[first] = 9 - diff.max(0);
+            largest[i] = largest[first] + diff;
+            smallest[first] = 1 - diff.min(0);
+            smallest[i] = smallest[first] + diff;
+            [26, diff - offset, rng.range(1, 16)]
+        };
+        let mut constants = constants.iter();
+        lines.extend(day24::BLOCK.iter().map(|ins| match ins.strip_suffix('_') {
+            Some(op) => format!("{}{}", op, constants.next().unwrap()),
+            None => ins.to_string(),
+        }));
+    }
+    let number =
+        |digits: [i64; day24::DIGITS]| Answer::Int(digits.iter().fold(0, |n, d| n * 10 + d));
+    Ok(Generated {
+        lines,
+        answers: Some((number(largest), number(smallest))),
+    })
+}
+
+fn day25(rng: &mut Rng, size: usize) -> Result<Generated, AocError> {
+    let side = size.max(2);
+    for _ in 0..ATTEMPTS {
+        let lines: Vec<String> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| match rng.below(4) {
+                        0 => '>',
+                        1 => 'v',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut herds: Herds = day25::Day25::parse(&lines)?;
+        if (0..STEP_LIMIT).any(|_| !herds.step()) {
+            return Ok(Generated::lines(lines));
+        }
+    }
+    Err(AocError::new(format!(
+        "no {0}x{0} map stopped moving within {1} steps",
+        side, STEP_LIMIT
+    )))
+}
@@ -0,0 +1,227 @@
+//! Rectangular 2D grid shared by the map puzzles.
+//!
+//! Cells are addressed by `(x, y)`, `x` being the column and `y` the row,
+//! both counted from the top left corner.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{first_line, AocError};
+use crate::point::Point2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a rectangular map, one cell per character. `cell` returns
+    /// `None` for characters that don't belong to the map.
+    pub fn parse<F>(lines: &[String], mut cell: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = first_line(lines)?.chars().count();
+        if width == 0 {
+            return Err(AocError::new("empty map").at_line(0));
+        }
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(AocError::new(format!("expected {} cells", width)).at_line(i));
+            }
+            for (j, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    AocError::new(format!("unexpected character {:?}", c))
+                        .at_line(i)
+                        .at_column(j)
+                })?;
+                cells.push(value);
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Grid::get`] for a point, which may lie anywhere on the plane.
+    pub fn get_point(&self, p: Point2) -> Option<&T> {
+        if p.x < 0 || p.y < 0 {
+            return None;
+        }
+        self.get(p.x as usize, p.y as usize)
+    }
+
+    fn offset_neighbors<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx)?;
+            let ny = y.checked_add_signed(dy)?;
+            (nx < width && ny < height).then_some((nx, ny))
+        })
+    }
+
+    /// Positions of the horizontal and vertical neighbours inside the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_neighbors(x, y, &OFFSETS4)
+    }
+
+    /// Positions of all neighbours inside the grid, diagonals included.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_neighbors(x, y, &OFFSETS8)
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a rectangular map of single digits.
+    pub fn parse_digits(lines: &[String]) -> Result<Grid<u32>, AocError> {
+        Grid::parse(lines, |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(lines: &[String]) -> Result<Grid<char>, AocError> {
+        Grid::parse(lines, Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
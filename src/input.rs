@@ -0,0 +1,110 @@
+//! Where puzzle inputs come from: a file, stdin or a directory of `dayN.txt` files.
+
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
+use crate::example_path;
+
+/// Environment variable naming the directory with the real puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Source named on the command line, `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        }
+    }
+
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::File(path) => Box::new(io::BufReader::new(File::open(path)?)),
+        })
+    }
+
+    pub fn read_lines(&self) -> io::Result<Vec<String>> {
+        self.reader()?.lines().collect()
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Input options shared by the `aoc` runner and the per-day binaries.
+#[derive(Debug, Default)]
+pub struct InputArgs {
+    /// Explicit `INPUT` argument, a path or `-`.
+    pub input: Option<String>,
+    /// `--input-dir`, takes precedence over `AOC_INPUT_DIR`.
+    pub input_dir: Option<PathBuf>,
+    /// `--example` forces the bundled example input.
+    pub example: bool,
+}
+
+impl InputArgs {
+    pub const USAGE: &'static str = "[--example | --input-dir DIR | INPUT | -]";
+
+    /// Consumes `arg` if it is an input option, taking its value from `rest`.
+    /// Returns `Ok(false)` for arguments it doesn't know.
+    pub fn parse_option<I>(&mut self, arg: &str, rest: &mut I) -> Result<bool, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        match arg {
+            "--example" => self.example = true,
+            "--input-dir" => {
+                let dir = rest.next().ok_or("--input-dir needs a value")?;
+                self.input_dir = Some(dir.into());
+            }
+            _ if arg.starts_with("--") => return Ok(false),
+            _ if self.input.is_none() => self.input = Some(arg.to_string()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// True when every day would read the same explicitly named input.
+    pub fn is_explicit(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Resolves the input of `day`: the explicit argument, the example with
+    /// `--example`, `dayN.txt` in the input directory, or the example as the
+    /// last resort.
+    pub fn source(&self, day: u32) -> Result<Source, String> {
+        if self.example && (self.input.is_some() || self.input_dir.is_some()) {
+            return Err("--example can't be combined with another input".to_string());
+        }
+        if let Some(input) = &self.input {
+            return Ok(Source::from_arg(input));
+        }
+        if self.example {
+            return Ok(Source::File(example_path(day)));
+        }
+        let dir = self
+            .input_dir
+            .clone()
+            .or_else(|| env::var_os(INPUT_DIR_VAR).map(PathBuf::from));
+        Ok(Source::File(match dir {
+            Some(dir) => dir.join(format!("day{}.txt", day)),
+            None => example_path(day),
+        }))
+    }
+}
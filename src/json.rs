@@ -0,0 +1,57 @@
+//! Just enough JSON to print answers for scripts, without pulling in serde.
+
+use std::fmt::Write;
+
+/// Quotes `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// Builds a single JSON object, keeping the fields in insertion order.
+#[derive(Debug, Default)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    /// Adds a field whose value is already encoded as JSON.
+    pub fn raw<V: ToString>(mut self, key: &str, value: V) -> Object {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn str(self, key: &str, value: &str) -> Object {
+        self.raw(key, string(value))
+    }
+}
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", string(key), value)?;
+        }
+        write!(f, "}}")
+    }
+}
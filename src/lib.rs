@@ -1,18 +1,120 @@
+//! Solutions of [Advent of Code 2021](https://adventofcode.com/2021).
+//!
+//! Every puzzle lives in its own module `dayN`, with a `DayN` type
+//! implementing [`solution::Solution`] next to the types it parses the input
+//! into. [`registry`] lists all of them for the binaries.
+
 use std::env;
+use std::error::Error;
 use std::fs::File;
 use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::input::InputArgs;
+
+pub mod bits;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+pub mod frames;
+pub mod gen;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod point;
+pub mod registry;
+pub mod repl;
+pub mod search;
+pub mod serve;
+pub mod solution;
 
+pub use grid::Grid;
+pub use point::{Point2, Point3};
+
+/// Lines of the input given to a per-day binary, see [`InputArgs`] for the options.
+/// The day is taken from the binary name, e.g. `day15`.
 pub fn get_lines() -> io::Result<impl Iterator<Item = String>> {
+    get_lines_with("", |_, _| Ok(false))
+}
+
+/// Like [`get_lines`] for binaries with options of their own. `option` sees
+/// every argument first, along with the remaining ones to take values from,
+/// and returns whether it consumed it. `usage` describes those options.
+pub fn get_lines_with<F>(usage: &str, mut option: F) -> io::Result<impl Iterator<Item = String>>
+where
+    F: FnMut(&str, &mut env::Args) -> Result<bool, String>,
+{
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
     let mut args = env::args();
     let prog = args.next().unwrap();
-    let filename = if let Some(param) = args.next() {
-        param
-    } else {
-        let base_name = prog.rsplit("/").next().unwrap();
-        format!("test_data/{}.txt", base_name)
-    };
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines().filter_map(|s| s.ok()))
+    let base_name = prog.rsplit('/').next().unwrap();
+    let day = base_name
+        .strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| invalid(format!("can't tell the day from {:?}", base_name)))?;
+    let mut input_args = InputArgs::default();
+    while let Some(arg) = args.next() {
+        if option(&arg, &mut args).map_err(invalid)? {
+            continue;
+        }
+        if !input_args.parse_option(&arg, &mut args).map_err(invalid)? {
+            return Err(invalid(format!(
+                "unexpected argument: {}\nusage: {} {}{}",
+                arg,
+                base_name,
+                usage,
+                InputArgs::USAGE
+            )));
+        }
+    }
+    let source = input_args.source(day).map_err(invalid)?;
+    let reader = source
+        .reader()
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", source, e)))?;
+    Ok(reader.lines().map_while(Result::ok))
+}
+
+/// Ends a binary whose `main` failed with the readable form of the error,
+/// rather than the `Debug` one returning it from `main` prints.
+pub fn exit_on_error(result: Result<(), Box<dyn Error>>) {
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Path to the bundled example input of the given day.
+pub fn example_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("test_data/day{}.txt", day))
+}
+
+pub fn read_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    let file = File::open(path)?;
+    io::BufReader::new(file).lines().collect()
 }
 
 pub fn print_type_of<T>(_: &T) {
@@ -0,0 +1,320 @@
+//! Points on the plane and in space, and boxes around them.
+//!
+//! Coordinates are `i64` so that differences and distances never need a cast.
+//! [`crate::Grid`] keeps addressing its cells with `(usize, usize)`, see
+//! [`crate::Grid::get_point`] to look a `Point2` up.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::error::{parse_at, AocError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+const OFFSETS4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Step of length 1 or 0 along each axis towards the sign of `self`.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The horizontal and vertical neighbours.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point2> {
+        OFFSETS4
+            .iter()
+            .map(move |&(dx, dy)| self + Point2::new(dx, dy))
+    }
+
+    /// All the neighbours, diagonals included, row by row.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point2> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point2::new(dx, dy)))
+            .filter(|&d| d != Point2::ORIGIN)
+            .map(move |d| self + d)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The points sharing a face, an edge or a corner with `self`.
+    pub fn neighbors26(self) -> impl Iterator<Item = Point3> {
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .map(|(dx, dy, dz)| Point3::new(dx, dy, dz))
+            .filter(|&d| d != Point3::ORIGIN)
+            .map(move |d| self + d)
+    }
+
+    fn to_array(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn from_array([x, y, z]: [i64; 3]) -> Point3 {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Point2 {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+macro_rules! arithmetic {
+    ($point:ident, $($axis:ident),+) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, k: i64) -> $point {
+                $point { $($axis: self.$axis * k),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+arithmetic!(Point2, x, y);
+arithmetic!(Point3, x, y, z);
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Parses `part` of `line` as `x,y`, pointing errors at their column in `line`.
+pub fn parse_point2(line: &str, part: &str) -> Result<Point2, AocError> {
+    match part.split(',').collect::<Vec<_>>()[..] {
+        [x, y] => Ok(Point2::new(parse_at(line, x)?, parse_at(line, y)?)),
+        _ => Err(AocError::new("expected `x,y`").at(line, part)),
+    }
+}
+
+/// Parses `part` of `line` as `x,y,z`, pointing errors at their column in `line`.
+pub fn parse_point3(line: &str, part: &str) -> Result<Point3, AocError> {
+    match part.split(',').collect::<Vec<_>>()[..] {
+        [x, y, z] => Ok(Point3::new(
+            parse_at(line, x)?,
+            parse_at(line, y)?,
+            parse_at(line, z)?,
+        )),
+        _ => Err(AocError::new("expected `x,y,z`").at(line, part)),
+    }
+}
+
+/// Rectangle between two corners, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds2 {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds2 {
+    /// Smallest rectangle holding all the `points`, `None` when there are none.
+    pub fn around(points: impl IntoIterator<Item = Point2>) -> Option<Bounds2> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Bounds2 {
+                min: first,
+                max: first,
+            },
+            |b, p| Bounds2 {
+                min: Point2::new(b.min.x.min(p.x), b.min.y.min(p.y)),
+                max: Point2::new(b.max.x.max(p.x), b.max.y.max(p.y)),
+            },
+        ))
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// All the points inside, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+/// Cuboid between two corners, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    /// Smallest cuboid holding all the `points`, `None` when there are none.
+    pub fn around(points: impl IntoIterator<Item = Point3>) -> Option<Bounds3> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Bounds3 {
+                min: first,
+                max: first,
+            },
+            |b, p| Bounds3 {
+                min: Point3::new(b.min.x.min(p.x), b.min.y.min(p.y), b.min.z.min(p.z)),
+                max: Point3::new(b.max.x.max(p.x), b.max.y.max(p.y), b.max.z.max(p.z)),
+            },
+        ))
+    }
+
+    /// Number of points inside.
+    pub fn volume(&self) -> u64 {
+        let d = self.max - self.min;
+        (d.x + 1) as u64 * (d.y + 1) as u64 * (d.z + 1) as u64
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    pub fn intersection(&self, other: &Bounds3) -> Option<Bounds3> {
+        let min = Point3::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Bounds3 { min, max })
+    }
+
+    /// All the points inside, `x` changing fastest.
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max);
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+/// One of the 24 ways to turn something around the origin without mirroring
+/// it, as a matrix of -1, 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// All 24 rotations, the identity first.
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut res = Vec::with_capacity(24);
+        for (k, axes) in permutations.iter().enumerate() {
+            // Swapping two axes, like the odd permutations do, mirrors.
+            let parity = if [0, 3, 4].contains(&k) { 1 } else { -1 };
+            for signs in 0..8 {
+                let sign = |i: usize| if signs >> i & 1 == 1 { -1 } else { 1 };
+                if sign(0) * sign(1) * sign(2) != parity {
+                    continue;
+                }
+                let mut m = [[0; 3]; 3];
+                for (row, &axis) in axes.iter().enumerate() {
+                    m[row][axis] = sign(row);
+                }
+                res.push(Rotation(m));
+            }
+        }
+        res
+    }
+
+    pub fn apply(&self, p: Point3) -> Point3 {
+        let p = p.to_array();
+        Point3::from_array(self.0.map(|row| (0..3).map(|i| row[i] * p[i]).sum()))
+    }
+}
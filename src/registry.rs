@@ -0,0 +1,187 @@
+//! Lookup table of all solved days, used by the `aoc` runner and the per-day binaries.
+
+use std::env;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+/// Environment variable naming a day whose solver panics on purpose, to check
+/// that one failing day doesn't take the others down with it.
+pub const PANIC_DAY_VAR: &str = "AOC_PANIC_DAY";
+
+type Solver = fn(&[String], &[u32]) -> Result<Run, AocError>;
+
+#[derive(Debug)]
+pub struct Day {
+    pub number: u32,
+    solve: Solver,
+}
+
+/// Answers of a single run of a day together with the wall-clock time of each step.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u32,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+impl Run {
+    pub fn print(&self) {
+        for p in &self.parts {
+            if p.answer.is_multiline() {
+                println!("Task{}:\n{}", p.part, p.answer);
+            } else {
+                println!("Task{}: {}", p.part, p.answer);
+            }
+        }
+    }
+}
+
+impl Day {
+    /// Solves the given part, or both of them when `part` is `None`.
+    /// The input is parsed only once.
+    pub fn solve(
+        &self,
+        lines: &[String],
+        part: Option<u32>,
+    ) -> Result<Vec<(u32, Answer)>, AocError> {
+        let run = self.solve_timed(lines, part)?;
+        Ok(run.parts.into_iter().map(|p| (p.part, p.answer)).collect())
+    }
+
+    /// Solves like [`Day::solve`] and measures the parsing and every part.
+    pub fn solve_timed(&self, lines: &[String], part: Option<u32>) -> Result<Run, AocError> {
+        let parts = match part {
+            Some(p) => vec![p],
+            None => vec![1, 2],
+        };
+        if env::var(PANIC_DAY_VAR).is_ok_and(|day| day == self.number.to_string()) {
+            panic!("{} is set to this day", PANIC_DAY_VAR);
+        }
+        (self.solve)(lines, &parts).map_err(|e| e.in_day(self.number))
+    }
+
+    /// Solves like [`Day::solve_timed`] and prints the answers.
+    pub fn run(&self, lines: &[String], part: Option<u32>) -> Result<Run, AocError> {
+        let run = self.solve_timed(lines, part)?;
+        run.print();
+        Ok(run)
+    }
+}
+
+fn solve<S: Solution>(lines: &[String], parts: &[u32]) -> Result<Run, AocError> {
+    let start = Instant::now();
+    let input = S::parse(lines)?;
+    let parse = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            Ok(PartRun {
+                part,
+                answer: answer.map_err(|e| AocError::new(format!("part {}: {}", part, e)))?,
+                duration: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+    Ok(Run { parse, parts })
+}
+
+macro_rules! days {
+    ($($number:literal => $solution:path),* $(,)?) => {
+        pub static DAYS: &[Day] = &[
+            $(Day {
+                number: $number,
+                solve: solve::<$solution>,
+            },)*
+        ];
+    };
+}
+
+days! {
+    1 => crate::day1::Day1,
+    2 => crate::day2::Day2,
+    3 => crate::day3::Day3,
+    4 => crate::day4::Day4,
+    5 => crate::day5::Day5,
+    6 => crate::day6::Day6,
+    7 => crate::day7::Day7,
+    8 => crate::day8::Day8,
+    9 => crate::day9::Day9,
+    10 => crate::day10::Day10,
+    11 => crate::day11::Day11,
+    12 => crate::day12::Day12,
+    13 => crate::day13::Day13,
+    14 => crate::day14::Day14,
+    15 => crate::day15::Day15,
+    16 => crate::day16::Day16,
+    17 => crate::day17::Day17,
+    18 => crate::day18::Day18,
+    19 => crate::day19::Day19,
+    20 => crate::day20::Day20,
+    21 => crate::day21::Day21,
+    22 => crate::day22::Day22,
+    23 => crate::day23::Day23,
+    24 => crate::day24::Day24,
+    25 => crate::day25::Day25,
+}
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// Days selected on the command line: `all`, a single day `15` or an inclusive range `3..9`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection(RangeInclusive<u32>);
+
+impl Selection {
+    pub fn days(&self) -> impl Iterator<Item = &'static Day> + '_ {
+        DAYS.iter().filter(move |d| self.0.contains(&d.number))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSelectionError(String);
+
+impl fmt::Display for ParseSelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bad day selection: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSelectionError {}
+
+impl FromStr for Selection {
+    type Err = ParseSelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseSelectionError(s.to_string());
+        if s == "all" {
+            return Ok(Selection(1..=u32::MAX));
+        }
+        let (start, end) = match s.split_once("..") {
+            Some((start, end)) => (start, end.trim_start_matches('=')),
+            None => (s, s),
+        };
+        let start = start.parse().map_err(|_| err())?;
+        let end = end.parse().map_err(|_| err())?;
+        if start > end {
+            return Err(err());
+        }
+        Ok(Selection(start..=end))
+    }
+}
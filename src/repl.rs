@@ -0,0 +1,225 @@
+//! Stepping through the simulations of some days by hand, for `aoc repl`.
+//!
+//! A day exposes its state as a [`Simulation`], and [`Repl`] reads commands
+//! like `step 10`, `show` or `run until sync` to drive it.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use crate::error::AocError;
+use crate::{day11, day13, day14, day20, day23, day4};
+
+/// Days with a simulation, see [`simulation`].
+pub const DAYS: &[u32] = &[4, 11, 13, 14, 20, 23];
+
+/// `run until` gives up after this many steps, for conditions that never hold.
+const RUN_LIMIT: usize = 10_000;
+
+const HELP: &str = "\
+step [N]          advance one or N steps
+show              print the current state
+run until COND    step until COND holds, COND being one of
+{conditions}
+reset             start over from the input
+help              print this
+quit              leave, like end of input";
+
+/// A puzzle state that advances one step at a time, printed with `Display`.
+pub trait Simulation: fmt::Display {
+    /// Advances one step and describes it, `None` when there's nothing left to do.
+    fn step(&mut self) -> Option<String>;
+
+    /// Whether the day's own `condition`, split into words, holds.
+    fn holds(&self, condition: &[&str]) -> Result<bool, String>;
+
+    /// The conditions [`Simulation::holds`] knows with what they mean, for `help`.
+    fn conditions(&self) -> &'static [(&'static str, &'static str)];
+}
+
+/// The number of a condition like `flashes 100`.
+pub fn condition_count(condition: &[&str]) -> Result<u64, String> {
+    match condition {
+        [_, n] => n
+            .parse()
+            .map_err(|_| format!("{} needs a number, not {:?}", condition[0], n)),
+        _ => Err(format!("{} needs a number", condition[0])),
+    }
+}
+
+/// Fails for days without a simulation.
+pub fn check_day(day: u32) -> Result<(), AocError> {
+    if DAYS.contains(&day) {
+        return Ok(());
+    }
+    let days: Vec<_> = DAYS.iter().map(|d| d.to_string()).collect();
+    Err(AocError::new(format!(
+        "day {} can't be stepped through, only days {} can",
+        day,
+        days.join(", ")
+    )))
+}
+
+/// The simulation of `day` over its input `lines`.
+pub fn simulation(day: u32, lines: &[String]) -> Result<Box<dyn Simulation>, AocError> {
+    check_day(day)?;
+    let simulation = match day {
+        4 => day4::simulation,
+        11 => day11::simulation,
+        13 => day13::simulation,
+        14 => day14::simulation,
+        20 => day20::simulation,
+        _ => day23::simulation,
+    };
+    simulation(lines).map_err(|e| e.in_day(day))
+}
+
+pub struct Repl {
+    day: u32,
+    lines: Vec<String>,
+    simulation: Box<dyn Simulation>,
+    /// Steps taken since the start.
+    steps: usize,
+    done: bool,
+}
+
+impl Repl {
+    pub fn new(day: u32, lines: Vec<String>) -> Result<Repl, AocError> {
+        let simulation = simulation(day, &lines)?;
+        Ok(Repl {
+            day,
+            lines,
+            simulation,
+            steps: 0,
+            done: false,
+        })
+    }
+
+    /// Advances one step, printing what happened.
+    fn step(&mut self, out: &mut impl Write) -> io::Result<bool> {
+        match self.simulation.step() {
+            Some(note) => {
+                self.steps += 1;
+                writeln!(out, "step {}: {}", self.steps, note)?;
+                Ok(true)
+            }
+            None => {
+                self.done = true;
+                writeln!(out, "done after {} step(s)", self.steps)?;
+                Ok(false)
+            }
+        }
+    }
+
+    fn holds(&self, condition: &[&str]) -> Result<bool, String> {
+        match condition {
+            ["done"] => Ok(self.done),
+            ["step", ..] => Ok(self.steps as u64 >= condition_count(condition)?),
+            _ => self.simulation.holds(condition),
+        }
+    }
+
+    /// Steps until `condition` holds, printing only the last step.
+    fn run_until(&mut self, condition: &[&str], out: &mut impl Write) -> io::Result<()> {
+        let mut last = None;
+        for _ in 0..RUN_LIMIT {
+            match self.holds(condition) {
+                Ok(true) => break,
+                Ok(false) => (),
+                Err(e) => return writeln!(out, "error: {}", e),
+            }
+            if self.done {
+                return writeln!(
+                    out,
+                    "done after {} step(s), {} never held",
+                    self.steps,
+                    condition.join(" ")
+                );
+            }
+            match self.simulation.step() {
+                Some(note) => {
+                    self.steps += 1;
+                    last = Some(note);
+                }
+                None => self.done = true,
+            }
+        }
+        if let Some(note) = last {
+            writeln!(out, "step {}: {}", self.steps, note)?;
+        }
+        match self.holds(condition) {
+            Ok(true) => writeln!(out, "{} holds at step {}", condition.join(" "), self.steps),
+            _ => writeln!(out, "gave up after {} steps", RUN_LIMIT),
+        }
+    }
+
+    /// Runs one command line, printing to `out`. Returns `false` on `quit`.
+    pub fn execute(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            [] => (),
+            ["step"] => {
+                self.step(out)?;
+            }
+            ["step", n] => match n.parse::<usize>() {
+                Ok(n) => {
+                    for _ in 0..n {
+                        if !self.step(out)? {
+                            break;
+                        }
+                    }
+                }
+                Err(_) => writeln!(out, "error: not a number of steps: {}", n)?,
+            },
+            ["show"] => writeln!(out, "{}", self.simulation.to_string().trim_end())?,
+            ["run", "until", ref condition @ ..] if !condition.is_empty() => {
+                self.run_until(condition, out)?
+            }
+            ["reset"] => {
+                *self = Repl::new(self.day, std::mem::take(&mut self.lines))
+                    .expect("the input parsed before");
+                writeln!(out, "back to the start")?;
+            }
+            ["help"] => {
+                let conditions: Vec<_> =
+                    [("done", "nothing left to do"), ("step N", "N steps taken")]
+                        .iter()
+                        .chain(self.simulation.conditions())
+                        .map(|(name, meaning)| format!("{:20}{:12}{}", "", name, meaning))
+                        .collect();
+                writeln!(
+                    out,
+                    "{}",
+                    HELP.replace("{conditions}", &conditions.join("\n"))
+                )?
+            }
+            ["quit"] | ["exit"] => return Ok(false),
+            _ => writeln!(out, "error: unknown command {:?}, try help", line.trim())?,
+        }
+        Ok(true)
+    }
+
+    /// Executes the commands of `input` until it ends or says `quit`,
+    /// prompting for each of them with `prompt`, e.g. on a terminal.
+    pub fn run(
+        &mut self,
+        input: impl BufRead,
+        out: &mut impl Write,
+        prompt: bool,
+    ) -> io::Result<()> {
+        let mut lines = input.lines();
+        loop {
+            if prompt {
+                write!(out, "day{}> ", self.day)?;
+                out.flush()?;
+            }
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => return Ok(()),
+            };
+            if !self.execute(&line, out)? {
+                return Ok(());
+            }
+            out.flush()?;
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! A puzzle describes its graph by implementing [`SearchProblem`] and picks
+//! [`dijkstra`], [`a_star`] or [`bfs`] to explore it.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// States reachable in one move, with the cost of that move.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, u64)>;
+
+    /// Estimate of the cost left to reach a goal. [`a_star`] only finds the
+    /// cheapest path when it never overestimates.
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub cost: u64,
+    /// Every state from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// Number of states taken from the frontier, a measure of the work done.
+    pub expanded: usize,
+}
+
+/// Visited states, numbered in the order they are discovered, with the best
+/// known cost and the state they were reached from.
+struct Visited<S> {
+    index: HashMap<S, usize>,
+    states: Vec<S>,
+    cost: Vec<u64>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Visited<S> {
+        Visited {
+            index: HashMap::new(),
+            states: Vec::new(),
+            cost: Vec::new(),
+            parent: Vec::new(),
+        }
+    }
+
+    /// Records reaching `state` for `cost` from `parent`. Returns its number
+    /// unless it was already reached at least as cheaply.
+    fn reach(&mut self, state: S, cost: u64, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(e) => {
+                let i = *e.get();
+                if cost >= self.cost[i] {
+                    return None;
+                }
+                self.cost[i] = cost;
+                self.parent[i] = parent;
+                Some(i)
+            }
+            Entry::Vacant(e) => {
+                let i = self.states.len();
+                self.states.push(e.key().clone());
+                e.insert(i);
+                self.cost.push(cost);
+                self.parent.push(parent);
+                Some(i)
+            }
+        }
+    }
+
+    fn result(&self, goal: usize, expanded: usize) -> SearchResult<S> {
+        let mut path = vec![self.states[goal].clone()];
+        let mut cur = goal;
+        while let Some(prev) = self.parent[cur] {
+            path.push(self.states[prev].clone());
+            cur = prev;
+        }
+        path.reverse();
+        SearchResult {
+            cost: self.cost[goal],
+            path,
+            expanded,
+        }
+    }
+}
+
+/// Cheapest path to a goal, guided by [`SearchProblem::heuristic`].
+pub fn a_star<P: SearchProblem>(problem: &P) -> Option<SearchResult<P::State>> {
+    let mut visited = Visited::new();
+    let mut frontier = BinaryHeap::new();
+    let start = problem.start();
+    let h = problem.heuristic(&start);
+    let i = visited.reach(start, 0, None).unwrap();
+    frontier.push(Reverse((h, 0, i)));
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, cost, i))) = frontier.pop() {
+        if cost > visited.cost[i] {
+            // A cheaper way to this state was found after it was queued.
+            continue;
+        }
+        let state = visited.states[i].clone();
+        expanded += 1;
+        if problem.is_goal(&state) {
+            return Some(visited.result(i, expanded));
+        }
+        for (next, step) in problem.successors(&state) {
+            let new_cost = cost + step;
+            let h = problem.heuristic(&next);
+            if let Some(j) = visited.reach(next, new_cost, Some(i)) {
+                frontier.push(Reverse((new_cost + h, new_cost, j)));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path to a goal, ignoring the heuristic.
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<SearchResult<P::State>> {
+    a_star(&NoHeuristic(problem))
+}
+
+struct NoHeuristic<'a, P>(&'a P);
+
+impl<P: SearchProblem> SearchProblem for NoHeuristic<'_, P> {
+    type State = P::State;
+
+    fn start(&self) -> P::State {
+        self.0.start()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.0.is_goal(state)
+    }
+
+    fn successors(&self, state: &P::State) -> Vec<(P::State, u64)> {
+        self.0.successors(state)
+    }
+}
+
+/// Path with the fewest moves to a goal. The move costs are ignored, the
+/// resulting cost is the number of moves.
+pub fn bfs<P: SearchProblem>(problem: &P) -> Option<SearchResult<P::State>> {
+    let mut visited = Visited::new();
+    let mut frontier = VecDeque::new();
+    frontier.push_back(visited.reach(problem.start(), 0, None).unwrap());
+    let mut expanded = 0;
+
+    while let Some(i) = frontier.pop_front() {
+        let state = visited.states[i].clone();
+        expanded += 1;
+        if problem.is_goal(&state) {
+            return Some(visited.result(i, expanded));
+        }
+        let cost = visited.cost[i];
+        for (next, _) in problem.successors(&state) {
+            if let Some(j) = visited.reach(next, cost + 1, Some(i)) {
+                frontier.push_back(j);
+            }
+        }
+    }
+    None
+}
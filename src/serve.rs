@@ -0,0 +1,204 @@
+//! A small HTTP server answering puzzles for other tools, for `aoc serve`.
+//!
+//! `POST /day/{n}/part/{p}` with the puzzle input as the body answers with a
+//! JSON object like the ones of `aoc --format json`:
+//! ```text
+//! curl --data-binary @test_data/day1.txt http://localhost:2021/day/1/part/1
+//! {"day":1,"part":1,"type":"int","answer":7,"duration_ns":1800,"parse_ns":9300}
+//! ```
+//! Failures answer with an error status and `{"error":"..."}`.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::Duration;
+
+use crate::json;
+use crate::registry;
+
+/// Inputs are a few kilobytes, anything much larger is a mistake.
+const MAX_BODY: usize = 16 << 20;
+
+/// Clients that stop sending in the middle of a request are dropped after this.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// A JSON object.
+    pub body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: json::Object::new().str("error", message).to_string(),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Writes the response, its body ending with a newline for the terminal.
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let body = format!("{}\n", self.body);
+        write!(
+            out,
+            "HTTP/1.1 {} {}\r\n\
+             Content-Type: application/json\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            body.len(),
+            body
+        )?;
+        out.flush()
+    }
+}
+
+/// Reads the request line, the headers and a body of `Content-Length` bytes.
+pub fn read_request<R: BufRead>(reader: &mut R) -> Result<Request, Response> {
+    let bad = |message: &str| Response::error(400, message);
+    let mut line = String::new();
+    let read_line = |reader: &mut R, line: &mut String| -> Result<(), Response> {
+        line.clear();
+        match reader.read_line(line) {
+            Ok(0) | Err(_) => Err(bad("incomplete request")),
+            Ok(_) => Ok(()),
+        }
+    };
+    read_line(reader, &mut line)?;
+    let (method, path) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, path, version] if version.starts_with("HTTP/1.") => {
+            (method.to_string(), path.to_string())
+        }
+        _ => return Err(bad("expected `METHOD PATH HTTP/1.1`")),
+    };
+    let mut length = None;
+    loop {
+        read_line(reader, &mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| bad("expected `Name: value` headers"))?;
+        if name.eq_ignore_ascii_case("content-length") {
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|_| bad("bad Content-Length"))?;
+            length = Some(value);
+        }
+    }
+    let length = match length {
+        Some(n) if n > MAX_BODY => return Err(Response::error(413, "input too large")),
+        Some(n) => n,
+        None if method == "POST" => {
+            return Err(Response::error(411, "the input needs a Content-Length"))
+        }
+        None => 0,
+    };
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad("body shorter than its Content-Length"))?;
+    Ok(Request { method, path, body })
+}
+
+/// Solves the part of the day `request` asks for.
+pub fn handle(request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments[..] {
+        ["day", day, "part", part] => (day, part),
+        _ => return Response::error(404, "expected /day/{n}/part/{p}"),
+    };
+    let day = match day.parse().ok().and_then(registry::get) {
+        Some(day) => day,
+        None => return Response::error(404, &format!("no solution for day {}", day)),
+    };
+    let part = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return Response::error(404, &format!("no such part: {}", part)),
+    };
+    if request.method != "POST" {
+        return Response::error(405, "post the input to solve it");
+    }
+    let input = match std::str::from_utf8(&request.body) {
+        Ok(input) => input,
+        Err(_) => return Response::error(400, "the input isn't UTF-8"),
+    };
+    let lines: Vec<String> = input.lines().map(String::from).collect();
+    // Some solvers still panic on inputs without an answer.
+    let run = panic::catch_unwind(AssertUnwindSafe(|| day.solve_timed(&lines, Some(part))));
+    match run {
+        Ok(Ok(run)) => {
+            let p = &run.parts[0];
+            Response::ok(
+                json::Object::new()
+                    .raw("day", day.number)
+                    .raw("part", p.part)
+                    .str("type", p.answer.kind())
+                    .raw("answer", p.answer.to_json())
+                    .raw("duration_ns", p.duration.as_nanos())
+                    .raw("parse_ns", run.parse.as_nanos())
+                    .to_string(),
+            )
+        }
+        Ok(Err(e)) => Response::error(422, &e.to_string()),
+        Err(_) => Response::error(500, "the solver gave up on this input"),
+    }
+}
+
+fn handle_connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let (request_line, response) = match read_request(&mut reader) {
+        Ok(request) => (
+            format!("{} {}", request.method, request.path),
+            handle(&request),
+        ),
+        Err(response) => ("-".to_string(), response),
+    };
+    eprintln!("{} {}", request_line, response.status);
+    response.write_to(&mut &stream)
+}
+
+/// Answers the connections of `listener` until it fails, each on its own thread.
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream) {
+                eprintln!("connection failed: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! Common interface of the puzzle solvers.
+
+use std::fmt;
+
+use crate::error::AocError;
+use crate::json;
+use num_bigint::BigUint;
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// A number too large for an `i64`.
+    Big(BigUint),
+    /// Several numbers, e.g. one per transmission in the input.
+    Ints(Vec<i64>),
+    Str(String),
+    /// Multi-line ASCII art, e.g. the folded paper of day 13.
+    Art(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+
+    /// Name of the variant, as used in the JSON output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Big(_) => "big",
+            Answer::Ints(_) => "ints",
+            Answer::Str(_) => "str",
+            Answer::Art(_) => "art",
+        }
+    }
+
+    /// The answer as a JSON number, array of numbers or string.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Int(v) => v.to_string(),
+            Answer::Big(v) => v.to_string(),
+            Answer::Ints(v) => {
+                let items: Vec<_> = v.iter().map(|n| n.to_string()).collect();
+                format!("[{}]", items.join(","))
+            }
+            Answer::Str(s) | Answer::Art(s) => json::string(s),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::Big(v) => write!(f, "{}", v),
+            Answer::Ints(v) => {
+                for (i, n) in v.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", n)?;
+                }
+                Ok(())
+            }
+            Answer::Str(s) | Answer::Art(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Self {
+                Answer::Int(v as i64)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+/// Numbers that fit in an `i64` are still answered as [`Answer::Int`].
+impl From<BigUint> for Answer {
+    fn from(v: BigUint) -> Self {
+        match i64::try_from(&v) {
+            Ok(v) => Answer::Int(v),
+            Err(_) => Answer::Big(v),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+/// Solver of one day. `parse` turns the input into the `Input` both parts
+/// work on, so it is parsed only once when solving both. A part fails on
+/// inputs that parse but have no answer, e.g. bingo boards that never win.
+pub trait Solution {
+    type Input;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
+}
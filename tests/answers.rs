@@ -0,0 +1,156 @@
+//! Runs every solver on the bundled examples and compares the answers with
+//! the `test_data/*.answers` manifests.
+//!
+//! A manifest holds one `partN: answer` line per part. Multi-line answers
+//! leave the header empty and follow on the next lines:
+//!
+//! ```text
+//! part1: 17
+//! part2:
+//! #####
+//! #...#
+//! #####
+//! ```
+//!
+//! `dayN_M.answers` belongs to the extra example `dayN_M.txt` of day N.
+
+use adventofcode2021::{read_lines, registry};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn parse_manifest(text: &str) -> Result<BTreeMap<u32, String>, String> {
+    let mut answers = BTreeMap::new();
+    let mut current: Option<(u32, Vec<&str>)> = None;
+    for line in text.lines() {
+        let header = line
+            .strip_prefix("part")
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(p, value)| Some((p.parse::<u32>().ok()?, value.trim())));
+        match header {
+            Some((part, value)) => {
+                if let Some((p, lines)) = current.take() {
+                    answers.insert(p, lines.join("\n"));
+                }
+                let lines = if value.is_empty() {
+                    vec![]
+                } else {
+                    vec![value]
+                };
+                current = Some((part, lines));
+            }
+            None => match &mut current {
+                Some((_, lines)) => lines.push(line),
+                None => return Err(format!("expected `partN:`, got {:?}", line)),
+            },
+        }
+    }
+    if let Some((p, lines)) = current {
+        answers.insert(p, lines.join("\n"));
+    }
+    Ok(answers)
+}
+
+/// Day of a manifest named `day15.answers` or `day12_2.answers`.
+fn day_of(path: &Path) -> Option<u32> {
+    let stem = path.file_stem()?.to_str()?;
+    let number = stem.strip_prefix("day")?;
+    number.split('_').next()?.parse().ok()
+}
+
+fn manifests() -> Vec<PathBuf> {
+    let mut paths: Vec<_> = fs::read_dir("test_data")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "answers"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn check(manifest: &Path) -> Vec<String> {
+    let name = manifest.display();
+    let day = match day_of(manifest).and_then(registry::get) {
+        Some(day) => day,
+        None => return vec![format!("{}: no such day", name)],
+    };
+    let expected = match fs::read_to_string(manifest)
+        .map_err(|e| e.to_string())
+        .and_then(|text| parse_manifest(&text))
+    {
+        Ok(expected) => expected,
+        Err(e) => return vec![format!("{}: {}", name, e)],
+    };
+    let input = manifest.with_extension("txt");
+    let answers = match read_lines(&input)
+        .map_err(|e| e.to_string())
+        .and_then(|lines| day.solve(&lines, None).map_err(|e| e.to_string()))
+    {
+        Ok(answers) => answers,
+        Err(e) => return vec![format!("{}: {}", input.display(), e)],
+    };
+    let mut mismatches = Vec::new();
+    for (part, answer) in answers {
+        let actual = answer.to_string();
+        match expected.get(&part) {
+            Some(want) if *want == actual => (),
+            Some(want) => mismatches.push(format!(
+                "day {} part {} ({}): expected {:?}, got {:?}",
+                day.number,
+                part,
+                input.display(),
+                want,
+                actual
+            )),
+            None => mismatches.push(format!("{}: no answer for part {}", name, part)),
+        }
+    }
+    mismatches
+}
+
+#[test]
+fn every_day_has_a_manifest() {
+    let covered: Vec<_> = manifests().iter().filter_map(|p| day_of(p)).collect();
+    let missing: Vec<_> = registry::DAYS
+        .iter()
+        .map(|d| d.number)
+        .filter(|n| !covered.contains(n))
+        .collect();
+    assert!(missing.is_empty(), "days without answers: {:?}", missing);
+}
+
+#[test]
+fn answers_match_manifests() {
+    let mismatches: Vec<_> = manifests().iter().flat_map(|m| check(m)).collect();
+    assert!(
+        mismatches.is_empty(),
+        "{} mismatch(es):\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn a_panicking_day_fails_alone() {
+    for jobs in [None, Some("2")] {
+        let mut aoc = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        aoc.args(["6..8", "--example"])
+            .env(registry::PANIC_DAY_VAR, "7");
+        if let Some(jobs) = jobs {
+            aoc.args(["--jobs", jobs]);
+        }
+        let output = aoc.output().unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(1), "{}", stderr);
+        assert!(stdout.contains("Task2: 26984457539"), "{}", stdout);
+        assert!(stdout.contains("Task2: 61229"), "{}", stdout);
+        assert!(
+            stderr.contains("day 7: the solver panicked: AOC_PANIC_DAY is set to this day"),
+            "{}",
+            stderr
+        );
+        assert!(stderr.contains("1 day(s) failed"), "{}", stderr);
+    }
+}
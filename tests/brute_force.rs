@@ -0,0 +1,206 @@
+//! Checks the clever algorithms against naive ones on small random inputs.
+
+use adventofcode2021::day14::{self, Day14};
+use adventofcode2021::day22::{self, RebootStep};
+use adventofcode2021::day5::{self, Day5};
+use adventofcode2021::solution::Solution;
+use adventofcode2021::{day6, day7};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+/// Simulates every lanternfish on its own.
+fn fish_one_by_one(timers: &[u64], days: u32) -> u64 {
+    let mut fish = timers.to_vec();
+    for _ in 0..days {
+        let mut born = 0;
+        for timer in fish.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                born += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+        fish.resize(fish.len() + born, 8);
+    }
+    fish.len() as u64
+}
+
+/// Builds the whole polymer and counts its elements.
+fn expand_polymer(
+    template: &str,
+    rules: &HashMap<(char, char), char>,
+    steps: u32,
+) -> HashMap<char, u64> {
+    let mut polymer: Vec<char> = template.chars().collect();
+    for _ in 0..steps {
+        let mut next = vec![polymer[0]];
+        for pair in polymer.windows(2) {
+            if let Some(&c) = rules.get(&(pair[0], pair[1])) {
+                next.push(c);
+            }
+            next.push(pair[1]);
+        }
+        polymer = next;
+    }
+    let mut counts = HashMap::new();
+    for c in polymer {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn element() -> impl Strategy<Value = char> {
+    prop::sample::select(vec!['B', 'C', 'H', 'N'])
+}
+
+fn polymer_manual() -> impl Strategy<Value = (String, Vec<String>)> {
+    let template = vec(element(), 1..10).prop_map(|t| t.into_iter().collect());
+    // Some pairs have no rule and are left alone.
+    let rules = vec(prop::option::of(element()), 16).prop_map(|inserted| {
+        let elements = ['B', 'C', 'H', 'N'];
+        inserted
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, c)| Some(format!("{}{} -> {}", elements[i / 4], elements[i % 4], c?)))
+            .collect()
+    });
+    (template, rules)
+}
+
+/// Short lines, some only a point, in every direction on a small map so that
+/// they overlap a lot. The map is around the origin or at the edges of the
+/// range of an `i64`.
+fn vent_lines() -> impl Strategy<Value = Vec<String>> {
+    let edge = || prop::sample::select(vec![i64::MIN + 20, 0, i64::MAX - 20]);
+    (edge(), edge(), vec(vent_line(), 1..40)).prop_map(|(x0, y0, lines)| {
+        lines
+            .into_iter()
+            .map(|(x1, y1, x2, y2)| format!("{},{} -> {},{}", x0 + x1, y0 + y1, x0 + x2, y0 + y2))
+            .collect()
+    })
+}
+
+fn vent_line() -> impl Strategy<Value = (i64, i64, i64, i64)> {
+    let direction = prop::sample::select(vec![
+        (1, 0),
+        (0, 1),
+        (1, 1),
+        (1, -1),
+        (-1, 0),
+        (0, -1),
+        (-1, -1),
+        (-1, 1),
+    ]);
+    (-6..=6i64, -6..=6i64, direction, 0..=8i64)
+        .prop_map(|(x, y, (dx, dy), len)| (x, y, x + dx * len, y + dy * len))
+}
+
+/// Many crabs close together, or a few far enough apart that the fuel
+/// doesn't fit in an `i32`.
+fn crab_positions() -> impl Strategy<Value = Vec<i32>> {
+    prop_oneof![vec(-100..300i32, 1..50), vec(-100_000..100_000i32, 1..6)]
+}
+
+type Cuboid = [(i64, i64); 3];
+
+/// Small cuboids around the origin.
+fn cuboid() -> impl Strategy<Value = Cuboid> {
+    let range = (-8..=8i64, 0..=6i64).prop_map(|(start, len)| (start, start + len));
+    [range.clone(), range.clone(), range]
+}
+
+/// Steps switching cuboids on and off, some of them the same as an earlier
+/// one or inside it, so that the same cubes are switched over and over.
+fn reboot_steps() -> impl Strategy<Value = Vec<String>> {
+    let step = (
+        any::<bool>(),
+        cuboid(),
+        any::<prop::sample::Index>(),
+        0..3u8,
+    );
+    vec(step, 1..12).prop_map(|steps| {
+        let mut cuboids: Vec<Cuboid> = Vec::new();
+        let mut lines = Vec::new();
+        for (on, fresh, earlier, reuse) in steps {
+            let cuboid = match (reuse, cuboids.is_empty()) {
+                (_, true) | (0, _) => fresh,
+                (1, _) => *earlier.get(&cuboids),
+                _ => earlier
+                    .get(&cuboids)
+                    .map(|(min, max)| (min + (max - min) / 3, max - (max - min) / 3)),
+            };
+            cuboids.push(cuboid);
+            let [x, y, z] = cuboid;
+            lines.push(format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                if on { "on" } else { "off" },
+                x.0,
+                x.1,
+                y.0,
+                y.1,
+                z.0,
+                z.1
+            ));
+        }
+        lines
+    })
+}
+
+proptest! {
+    #[test]
+    fn day5_sweep_matches_raster(lines in vent_lines()) {
+        let vents = Day5::parse(&lines).unwrap();
+        for diagonals in [false, true] {
+            prop_assert_eq!(
+                day5::count_overlaps(&vents, diagonals),
+                day5::count_overlaps_raster(&vents, diagonals) as u128
+            );
+        }
+    }
+
+    #[test]
+    fn day6_buckets_match_every_fish(timers in vec(0..=8u64, 1..20), days in 0..60u32) {
+        prop_assert_eq!(day6::fish_after(&timers, days), fish_one_by_one(&timers, days));
+    }
+
+    #[test]
+    fn day7_closed_forms_match_exhaustive_search(positions in crab_positions()) {
+        prop_assert_eq!(
+            day7::min_fuel_constant_rate(&positions),
+            day7::min_fuel_exhaustive(&positions, day7::fuel_to_new_position)
+        );
+        prop_assert_eq!(
+            day7::min_fuel_increasing_rate(&positions),
+            day7::min_fuel_exhaustive(&positions, day7::fuel_to_new_position2)
+        );
+    }
+
+    #[test]
+    fn day14_pair_counts_match_expansion((template, rules) in polymer_manual(), steps in 0..8u32) {
+        let mut lines = vec![template, String::new()];
+        lines.extend(rules);
+        let manual = Day14::parse(&lines).unwrap();
+        prop_assert_eq!(
+            day14::element_counts(&manual, steps),
+            expand_polymer(manual.template(), manual.rules(), steps)
+        );
+    }
+
+    #[test]
+    fn day22_cuboids_match_voxels(lines in reboot_steps()) {
+        let steps: Vec<RebootStep> = lines.iter().map(|l| l.parse().unwrap()).collect();
+        prop_assert_eq!(day22::task2(&steps), day22::task1(&steps) as u64);
+    }
+}
+
+#[test]
+fn day22_cuboid_switched_on_off_and_on_again() {
+    let steps: Vec<RebootStep> = ["on", "off", "on"]
+        .iter()
+        .map(|state| format!("{} x=0..1,y=0..1,z=0..1", state).parse().unwrap())
+        .collect();
+    assert_eq!(day22::task2(&steps), 8);
+    assert_eq!(day22::task1(&steps), 8);
+}
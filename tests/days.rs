@@ -0,0 +1,352 @@
+//! Checks the options and edge cases of single days on small handmade inputs.
+
+use adventofcode2021::day1::{self, SweepReport};
+use adventofcode2021::day11::Day11;
+use adventofcode2021::day2::{self, AimingSubmarine, Command, Day2, SimpleSubmarine, Submarine};
+use adventofcode2021::day24::{self, Day24};
+use adventofcode2021::day25::Day25;
+use adventofcode2021::day3::Day3;
+use adventofcode2021::day4::{self, Day4, Win};
+use adventofcode2021::day8::Day8;
+use adventofcode2021::day9::Day9;
+use adventofcode2021::read_lines;
+use adventofcode2021::solution::{Answer, Solution};
+use num_bigint::BigUint;
+use std::io::Write;
+use std::process::{Command as Process, Stdio};
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+/// Runs the binary at `path` with `input` on stdin, returning whether it
+/// succeeded, its stdout and its stderr.
+fn run(path: &str, args: &[&str], input: &str) -> (bool, String, String) {
+    let mut child = Process::new(path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let text = |bytes| String::from_utf8(bytes).unwrap();
+    (
+        output.status.success(),
+        text(output.stdout),
+        text(output.stderr),
+    )
+}
+
+const DEPTHS: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+#[test]
+fn binaries_print_readable_errors() {
+    let (ok, _, stderr) = run(env!("CARGO_BIN_EXE_day5"), &["-"], "1,1 -> 2,2\nx\n");
+    assert!(!ok);
+    assert_eq!(stderr, "error: day 5: line 2: expected `x1,y1 -> x2,y2`\n");
+}
+
+fn report(window: usize, increases: usize, decreases: usize, plateaus: usize) -> SweepReport {
+    SweepReport {
+        window,
+        increases,
+        decreases,
+        plateaus,
+        longest_rise: None,
+    }
+}
+
+#[test]
+fn day1_sweeps_any_window() {
+    let sweep = |window| day1::sweep(DEPTHS.lines(), window).unwrap();
+    // Sums 607, 618, 618, 617, 647, 716, 769, 792 rise from lines 4-6 to 8-10.
+    let three = sweep(3);
+    assert_eq!(
+        three,
+        SweepReport {
+            longest_rise: Some((3, 9)),
+            ..report(3, 5, 1, 1)
+        }
+    );
+    assert_eq!(three.longest_rise_len(), 4);
+    // Sums 399, 408, 418, 410, 407, 447, 509, 529, 523.
+    assert_eq!(
+        sweep(2),
+        SweepReport {
+            longest_rise: Some((4, 8)),
+            ..report(2, 5, 3, 0)
+        }
+    );
+    // A single window has nothing to compare with.
+    assert_eq!(sweep(10), report(10, 0, 0, 0));
+    assert_eq!(sweep(10).longest_rise_len(), 0);
+    let (ok, stdout, _) = run(env!("CARGO_BIN_EXE_day1"), &["--window", "2", "-"], DEPTHS);
+    assert!(ok);
+    assert_eq!(
+        stdout,
+        "window 2: 5 increases, 3 decreases, 0 plateaus\n\
+         longest rise: 3 increases in a row, lines 5 to 9\n"
+    );
+}
+
+#[test]
+fn day1_counts_plateaus_and_falls() {
+    let sweep = |depths: &str, window| day1::sweep(depths.split(' '), window).unwrap();
+    assert_eq!(
+        sweep("1 1 1 2 2 1", 1),
+        SweepReport {
+            longest_rise: Some((2, 3)),
+            ..report(1, 1, 1, 3)
+        }
+    );
+    // Of two rises as long, the first one is the longest.
+    assert_eq!(sweep("1 2 0 1", 1).longest_rise, Some((0, 1)));
+    assert_eq!(sweep("3 2 1", 1), report(1, 0, 2, 0));
+    let err = day1::sweep(["1", "2", "x"], 2).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 1: bad value \"x\": invalid digit found in string"
+    );
+}
+
+fn win(board: usize, draws: usize, number: u32, score: u64) -> Win {
+    Win {
+        board,
+        draws,
+        number,
+        score,
+    }
+}
+
+/// Goes forward and down, takes both back, then goes up and forward.
+const COURSE: &str = "forward 5\ndown 5\nforward 8\nback 2\nup 3\nforward 2\n";
+
+/// Where `submarine` ends up on `course`.
+fn end_of<S: Submarine>(mut submarine: S, course: &str) -> String {
+    let commands = Day2::parse(&lines(course)).unwrap();
+    day2::navigate(&mut submarine, &commands, |_, _| ());
+    day2::describe(&submarine)
+}
+
+#[test]
+fn day2_back_undoes_moves() {
+    let course = Day2::parse(&lines(COURSE)).unwrap();
+    assert_eq!(Day2::part1(&course).unwrap(), Answer::Int(7 * -3));
+    assert_eq!(Day2::part2(&course).unwrap(), Answer::Int(7 * -6));
+    let home = format!("{}back\n", COURSE);
+    assert_eq!(
+        end_of(SimpleSubmarine::default(), &home),
+        "position 0, depth 0"
+    );
+    assert_eq!(
+        end_of(AimingSubmarine::default(), &home),
+        "position 0, depth 0, aim 0"
+    );
+    // Commands that didn't go through `parse` can't go back further than the start.
+    let mut submarine = SimpleSubmarine::default();
+    let commands = [Command::Back(Some(3))];
+    day2::navigate(&mut submarine, &commands, |_, _| ());
+    assert_eq!(day2::describe(&submarine), "position 0, depth 0");
+}
+
+#[test]
+fn day2_traces_every_move() {
+    let (ok, stdout, _) = run(env!("CARGO_BIN_EXE_day2"), &["--trace", "-"], COURSE);
+    assert!(ok);
+    let trace: Vec<_> = stdout.lines().map(str::trim_end).collect();
+    assert_eq!(
+        trace[..9],
+        [
+            "simple submarine:",
+            "  line 1: forward 5        position 5, depth 0",
+            "  line 2: down 5           position 5, depth 5",
+            "  line 3: forward 8        position 13, depth 5",
+            "  undo line 3: forward 8   position 5, depth 5",
+            "  undo line 2: down 5      position 5, depth 0",
+            "  line 5: up 3             position 5, depth -3",
+            "  line 6: forward 2        position 7, depth -3",
+            "aiming submarine:",
+        ]
+    );
+    assert_eq!(
+        trace[11],
+        "  line 3: forward 8        position 13, depth 40, aim 5"
+    );
+    assert_eq!(trace[16..], ["Task1: -21", "Task2: -42"]);
+}
+
+#[test]
+fn day2_rejects_going_back_too_far() {
+    let err = Day2::parse(&lines("forward 1\ndown 2\nback 1\nback 2\n")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 4: can't go back 2 moves, there are only 1"
+    );
+}
+
+#[test]
+fn day3_skips_bits_every_line_shares() {
+    // Both lines start with 1, only the second bit tells them apart.
+    let report = Day3::parse(&lines("10\n11\n")).unwrap();
+    // Ties count as zeros, so gamma is 10 and epsilon 01.
+    assert_eq!(Day3::part1(&report).unwrap(), Answer::Int(2));
+    assert_eq!(Day3::part2(&report).unwrap(), Answer::Int(0b11 * 0b10));
+    let report = Day3::parse(&lines("0110\n0111\n0101\n")).unwrap();
+    assert_eq!(Day3::part2(&report).unwrap(), Answer::Int(0b0111 * 0b0101));
+}
+
+#[test]
+fn day3_answers_wide_reports_exactly() {
+    let report = Day3::parse(&lines(
+        "1010001000011000100001000011001000100001111111000011111001010110011111
+0011001111101100100100111001110111110000000010110011100111110110000100
+1000001000101111001111100011100010010110101000100110011101111000010101
+0110010101101110000001011000000100010101110011100010000010011000010010
+0110111010101011100100100101010011000111101101011101110000011001011101
+1010010100100010101110000010001101101101000010101111001001100001100011
+0101001101111001010011011000010010101100011101001000000010110001101000",
+    ))
+    .unwrap();
+    let big = |n: &str| Answer::Big(n.parse::<BigUint>().unwrap());
+    let power = Day3::part1(&report).unwrap();
+    assert_eq!(power, big("165162601273508713283138342341487672904242"));
+    assert_eq!(power.kind(), "big");
+    assert_eq!(
+        power.to_json(),
+        "165162601273508713283138342341487672904242"
+    );
+    assert_eq!(
+        Day3::part2(&report).unwrap(),
+        big("306414693225734401637350455434338151246753")
+    );
+}
+
+#[test]
+fn day4_boards_of_any_size() {
+    let bingo = Day4::parse(&lines("5,2,4\n\n1 2 3\n4 5 6\n\n1 2\n3 4\n5 6\n")).unwrap();
+    // 2 completes the middle column of the first board, 1 + 3 + 4 + 6 are left.
+    assert_eq!(Day4::part1(&bingo).unwrap(), Answer::Int(28));
+    assert_eq!(
+        Day4::part2(&bingo).unwrap_err().to_string(),
+        "board 2 never wins"
+    );
+    assert!(Day4::parse(&lines("1\n\n1 2 3\n4 5\n")).is_err());
+}
+
+#[test]
+fn day4_ranks_boards_in_winning_order() {
+    let bingo = Day4::parse(&read_lines("test_data/day4.txt").unwrap()).unwrap();
+    assert_eq!(
+        day4::ranking(&bingo, false),
+        [
+            win(2, 12, 24, 4512),
+            win(0, 14, 16, 2192),
+            win(1, 15, 13, 1924)
+        ]
+    );
+}
+
+#[test]
+fn day4_diagonals_win_on_square_boards() {
+    let bingo = Day4::parse(&read_lines("test_data/day4.txt").unwrap()).unwrap();
+    // 4, 9, 23, 11 and 2 run from the top right to the bottom left of board 3.
+    assert_eq!(day4::ranking(&bingo, true)[0], win(2, 8, 2, 494));
+    let bingo = Day4::parse(&lines("1,4,5\n\n1 2\n3 4\n5 6\n")).unwrap();
+    assert_eq!(day4::ranking(&bingo, true), []);
+    assert_eq!(
+        Day4::part1(&bingo).unwrap_err().to_string(),
+        "no board wins"
+    );
+}
+
+#[test]
+fn day8_rejects_entries_it_cant_decode() {
+    let err = |line: &str| Day8::parse(&lines(line)).unwrap_err().to_string();
+    assert_eq!(
+        err("a b c d e f g ab abc abcd | ab ab ab ab"),
+        "line 1: the patterns don't identify digit 3"
+    );
+    let example = read_lines("test_data/day8.txt").unwrap();
+    let (patterns, _) = example[0].split_once(" | ").unwrap();
+    assert_eq!(
+        err(&format!("{} | fdgacbe cefdb cefbgd gc", patterns)),
+        "line 1, column 83: output digit \"gc\" is none of the patterns"
+    );
+}
+
+#[test]
+fn day9_multiplies_the_basins_there_are() {
+    let basins = |map: &str| Day9::part2(&Day9::parse(&lines(map)).unwrap()).unwrap();
+    assert_eq!(basins("919\n999"), Answer::Int(1));
+    assert_eq!(basins("5"), Answer::Int(1));
+    // A basin of two and one of one.
+    assert_eq!(basins("1191\n9999"), Answer::Int(2));
+}
+
+#[test]
+fn day11_gives_up_on_octopuses_that_never_sync() {
+    // The first flash lifts the second octopus to 2, always one step behind.
+    let map = Day11::parse(&lines("80")).unwrap();
+    assert_eq!(
+        Day11::part2(&map).unwrap_err().to_string(),
+        "the octopuses don't all flash at once within 10000 steps"
+    );
+}
+
+#[test]
+fn day24_reports_arithmetic_errors_with_their_line() {
+    let fails = |program: &str| {
+        let program = day24::parse_program(&lines(program)).unwrap();
+        day24::run(&program, &[5]).unwrap_err().to_string()
+    };
+    assert_eq!(
+        fails("inp x\nmul x 9223372036854775807\nmul x 2"),
+        "line 2: mul x 9223372036854775807: overflow"
+    );
+    assert_eq!(
+        fails("inp x\nadd x 9223372036854775807"),
+        "line 2: add x 9223372036854775807: overflow"
+    );
+    assert_eq!(
+        fails("inp x\nmod x 0"),
+        "line 2: mod x 0: modulo by a non-positive number"
+    );
+    assert_eq!(
+        fails("inp x\nmul x -1\nmod x 3"),
+        "line 3: mod x 3: modulo of a negative number"
+    );
+}
+
+#[test]
+fn day24_rejects_pushes_that_dont_fit_in_base_26() {
+    let mut program = read_lines("test_data/day24.txt").unwrap();
+    // The first digit plus 17 can still match the last one, but 9 + 17 is
+    // no longer a digit of `z`.
+    program[15] = "add y 17".to_string();
+    program[239] = "add x -17".to_string();
+    assert_eq!(
+        Day24::parse(&program).err().unwrap().to_string(),
+        "line 16: block 1 pushes a digit plus 17, which doesn't fit in base 26"
+    );
+}
+
+#[test]
+fn day25_notices_herds_that_never_stop() {
+    for map in [">.", ">..v\n....\n..v.", ">.v\n..."] {
+        let herds = Day25::parse(&lines(map)).unwrap();
+        let err = Day25::part1(&herds).unwrap_err();
+        assert_eq!(err.to_string(), "the herds never stop", "{}", map);
+    }
+    let herds = Day25::parse(&read_lines("test_data/day25.txt").unwrap()).unwrap();
+    assert_eq!(Day25::part1(&herds).unwrap(), Answer::Int(58));
+    // Every cucumber is blocked from the start.
+    let herds = Day25::parse(&lines(">v\n.v")).unwrap();
+    assert_eq!(Day25::part1(&herds).unwrap(), Answer::Int(1));
+}
@@ -0,0 +1,66 @@
+//! Checks that generated inputs are reproducible and valid puzzle inputs.
+
+use adventofcode2021::error::AocError;
+use adventofcode2021::solution::Solution;
+use adventofcode2021::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20, day21,
+    day22, day23, day24, day25, day3, day4, day5, day6, day7, day8, day9, gen,
+};
+
+fn parses<S: Solution>(lines: &[String]) -> Result<(), AocError> {
+    S::parse(lines).map(|_| ())
+}
+
+type Parser = fn(&[String]) -> Result<(), AocError>;
+
+/// `Solution::parse` of every day, in order.
+const PARSERS: [Parser; 25] = [
+    parses::<day1::Day1>,
+    parses::<day2::Day2>,
+    parses::<day3::Day3>,
+    parses::<day4::Day4>,
+    parses::<day5::Day5>,
+    parses::<day6::Day6>,
+    parses::<day7::Day7>,
+    parses::<day8::Day8>,
+    parses::<day9::Day9>,
+    parses::<day10::Day10>,
+    parses::<day11::Day11>,
+    parses::<day12::Day12>,
+    parses::<day13::Day13>,
+    parses::<day14::Day14>,
+    parses::<day15::Day15>,
+    parses::<day16::Day16>,
+    parses::<day17::Day17>,
+    parses::<day18::Day18>,
+    parses::<day19::Day19>,
+    parses::<day20::Day20>,
+    parses::<day21::Day21>,
+    parses::<day22::Day22>,
+    parses::<day23::Day23>,
+    parses::<day24::Day24>,
+    parses::<day25::Day25>,
+];
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=25 {
+        let first = gen::generate(day, 2021, None).unwrap();
+        let again = gen::generate(day, 2021, None).unwrap();
+        assert_eq!(first.lines, again.lines, "day {}", day);
+        assert_eq!(first.answers, again.answers, "day {}", day);
+    }
+}
+
+#[test]
+fn generated_inputs_parse() {
+    for (day, parse) in (1..=25).zip(PARSERS) {
+        for seed in 0..3 {
+            // Small, locating day 19's scanners is slow in debug builds.
+            let generated = gen::generate(day, seed, Some(5)).unwrap();
+            if let Err(e) = parse(&generated.lines) {
+                panic!("day {} seed {}: {}", day, seed, e.in_day(day));
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Talks plain HTTP to `aoc serve` on localhost.
+
+use adventofcode2021::serve;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+
+fn start() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve::serve(listener));
+    addr
+}
+
+/// Sends a request and returns the status code and the body of the response.
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, body.trim_end().to_string())
+}
+
+#[test]
+fn answers_posted_inputs() {
+    let addr = start();
+    let input = fs::read_to_string("test_data/day1.txt").unwrap();
+    let (status, body) = request(addr, "POST", "/day/1/part/2", &input);
+    assert_eq!(status, 200);
+    assert!(
+        body.starts_with(r#"{"day":1,"part":2,"type":"int","answer":5,"duration_ns":"#),
+        "{}",
+        body
+    );
+}
+
+#[test]
+fn reports_errors_as_json() {
+    let addr = start();
+    assert_eq!(
+        request(addr, "POST", "/day/1/part/1", "1\nx\n"),
+        (
+            422,
+            r#"{"error":"day 1: line 2, column 1: bad value \"x\": invalid digit found in string"}"#
+                .to_string()
+        )
+    );
+    // Parses, but no board ever wins.
+    assert_eq!(
+        request(addr, "POST", "/day/4/part/1", "9\n\n1 2\n3 4\n"),
+        (
+            422,
+            r#"{"error":"day 4: part 1: no board wins"}"#.to_string()
+        )
+    );
+    assert_eq!(request(addr, "POST", "/day/26/part/1", "").0, 404);
+    assert_eq!(request(addr, "POST", "/day/1/part/3", "").0, 404);
+    assert_eq!(request(addr, "GET", "/day/1/part/1", "").0, 405);
+}